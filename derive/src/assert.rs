@@ -0,0 +1,60 @@
+use quote::quote;
+
+pub fn expand(args: proc_macro2::TokenStream, input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(param) = input.generics.params.first() {
+        return Err(syn::Error::new_spanned(param, "generic type is not supported"));
+    }
+
+    let name = &input.ident;
+    let mut asserts = Vec::new();
+
+    let parser = syn::meta::parser(|meta| {
+        let assert = if meta.path.is_ident("size") {
+            let size: syn::Expr = meta.value()?.parse()?;
+            quote!(::type_traits::SizeIs::<#name, { #size }>::ASSERT)
+        } else if meta.path.is_ident("size_at_most") {
+            let size: syn::Expr = meta.value()?.parse()?;
+            quote!(::type_traits::SizeAtMost::<#name, { #size }>::ASSERT)
+        } else if meta.path.is_ident("size_at_least") {
+            let size: syn::Expr = meta.value()?.parse()?;
+            quote!(::type_traits::SizeAtLeast::<#name, { #size }>::ASSERT)
+        } else if meta.path.is_ident("align") {
+            let align: syn::Expr = meta.value()?.parse()?;
+            quote!(::type_traits::AlignIs::<#name, { #align }>::ASSERT)
+        } else if meta.path.is_ident("align_at_least") {
+            let align: syn::Expr = meta.value()?.parse()?;
+            quote!(::type_traits::AlignAtLeast::<#name, { #align }>::ASSERT)
+        } else if meta.path.is_ident("align_at_most") {
+            let align: syn::Expr = meta.value()?.parse()?;
+            quote!(::type_traits::AlignAtMost::<#name, { #align }>::ASSERT)
+        } else if meta.path.is_ident("zst") {
+            quote!(::type_traits::Assert::<#name>::IS_ZST)
+        } else if meta.path.is_ident("not_zst") {
+            quote!(::type_traits::Assert::<#name>::IS_NOT_ZST)
+        } else if meta.path.is_ident("no_drop") {
+            quote!(::type_traits::Assert::<#name>::NO_NEED_DROP)
+        } else if meta.path.is_ident("no_padding") {
+            quote!(::type_traits::Assert::<#name>::HAS_NO_PADDING)
+        } else if meta.path.is_ident("niche") {
+            quote!(::type_traits::Assert::<#name>::HAS_NICHE)
+        } else if meta.path.is_ident("null_pointer_optimized") {
+            quote!(::type_traits::Assert::<#name>::IS_NULL_POINTER_OPTIMIZED)
+        } else {
+            return Err(meta.error("unsupported layout property"));
+        };
+
+        asserts.push(assert);
+        Ok(())
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+
+    Ok(quote! {
+        #input
+
+        const _: () = {
+            #(
+                let _ = #asserts;
+            )*
+        };
+    })
+}
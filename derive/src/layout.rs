@@ -0,0 +1,36 @@
+use quote::quote;
+
+pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = crate::struct_fields(&input)?;
+    let mut generics = crate::add_static_bounds(&input.generics)?;
+    let where_clause = generics.make_where_clause();
+    for field in fields.iter() {
+        let ty = &field.ty;
+        where_clause.predicates.push(syn::parse_quote!(#ty: ::type_traits::TypeLayout));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = &input.ident;
+
+    let len = fields.len();
+    let fields = fields.iter().enumerate().map(|(idx, field)| {
+        let (field_name, member) = crate::field_member(idx, field);
+        let ty = &field.ty;
+        quote! {
+            (
+                #field_name,
+                ::type_traits::TypeInfo::of::<#ty>(),
+                ::core::mem::offset_of!(Self, #member),
+                <#ty as ::type_traits::TypeLayout>::PADDING_BYTES,
+            )
+        }
+    });
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::type_traits::TypeLayout for #name #ty_generics #where_clause {
+            const FIELDS: &'static [::type_traits::Field] = &::type_traits::__layout_fields::<#len>(
+                [#(#fields),*],
+                ::core::mem::size_of::<Self>()
+            );
+        }
+    })
+}
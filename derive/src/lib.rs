@@ -0,0 +1,231 @@
+//!Derive macros for [type_traits](https://docs.rs/type_traits)
+//!
+//!Should be used via `derive` feature of `type_traits`
+
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+mod layout;
+mod assert;
+mod tid;
+
+#[proc_macro_derive(TypeLayout)]
+///Implements `TypeLayout` for struct, describing all its fields.
+///
+///All field types must be `'static`, hence struct cannot have lifetime parameters.
+///Field types must implement `TypeLayout` too, so that padding within nested fields is accounted.
+///
+///```
+///use type_traits::{Assert, Type, TypeLayout};
+///
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Packed {
+///    a: u32,
+///    b: u32,
+///}
+///
+///let _ = Assert::<Packed>::HAS_NO_PADDING;
+///assert_eq!(Type::<Packed>::fields()[1].offset(), 4);
+///```
+///
+///```compile_fail
+///use type_traits::{Assert, TypeLayout};
+///
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Padded {
+///    a: u8,
+///    b: u32,
+///}
+///
+///let _ = Assert::<Padded>::HAS_NO_PADDING;
+///```
+///
+///```compile_fail
+///use type_traits::{Assert, TypeLayout};
+///
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Padded {
+///    a: u8,
+///    b: u32,
+///}
+///
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Outer {
+///    inner: Padded,
+///}
+///
+///let _ = Assert::<Outer>::HAS_NO_PADDING;
+///```
+///
+///```compile_fail
+///use type_traits::TypeLayout;
+///
+///struct Opaque(u32);
+///
+///#[derive(TypeLayout)]
+///struct Outer {
+///    inner: Opaque,
+///}
+///```
+pub fn type_layout(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match layout::derive(input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Tid)]
+///Implements `Tid`, allowing type erasure of type with lifetime.
+///
+///Type can have at most one lifetime parameter, and all type parameters must be `'static`.
+///
+///```
+///use type_traits::{AnyRef, Tid};
+///
+///#[derive(Tid)]
+///struct Request<'a> {
+///    path: &'a str,
+///}
+///
+///#[derive(Tid)]
+///struct Response<T> {
+///    body: T,
+///}
+///
+///let path = String::from("/index");
+///let request = Request { path: &path };
+///let erased = AnyRef::new(&request);
+///assert_eq!(erased.downcast_ref::<Request>().unwrap().path, "/index");
+///assert!(erased.downcast_ref::<Response<u8>>().is_none());
+///```
+///
+///Multiple lifetimes are rejected, as only `'a` is tracked by erased types:
+///`Request<'a, 'b>` would have the same id as `Request<'a, 'c>`, allowing to downcast into reference with unrelated lifetime.
+///
+///```compile_fail
+///use type_traits::Tid;
+///
+///#[derive(Tid)]
+///struct Request<'a, 'b> {
+///    path: &'a str,
+///    query: &'b str,
+///}
+///```
+pub fn tid(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match tid::derive(input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_attribute]
+///Pins layout of the type, by asserting its properties at compile time.
+///
+///Type must not be generic.
+///
+///Supported properties:
+///
+///- `size = N` - size is exactly `N`;
+///- `size_at_most = N` - size is less or equal to `N`;
+///- `size_at_least = N` - size is greater or equal to `N`;
+///- `align = N` - minimum alignment is exactly `N`;
+///- `align_at_least = N` - minimum alignment is greater or equal to `N`;
+///- `align_at_most = N` - minimum alignment is less or equal to `N`;
+///- `zst` - type is ZST;
+///- `not_zst` - type is not ZST;
+///- `no_drop` - type requires no call to `Drop::drop`;
+///- `no_padding` - type has no padding between its fields, requires `TypeLayout`;
+///- `niche` - `Option` of type is the same size;
+///- `null_pointer_optimized` - `Option` of type is pointer sized.
+///
+///```
+///use type_traits::{layout, TypeLayout};
+///
+///#[layout(size = 16, align = 8, no_padding, not_zst, no_drop)]
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Header {
+///    len: u64,
+///    flags: u32,
+///    kind: u32,
+///}
+///```
+///
+///```compile_fail
+///use type_traits::layout;
+///
+///#[layout(size = 8)]
+///struct Header {
+///    len: u64,
+///    flags: u32,
+///}
+///```
+///
+///```compile_fail
+///use type_traits::{layout, TypeLayout};
+///
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Padded {
+///    a: u8,
+///    b: u32,
+///}
+///
+///#[layout(no_padding)]
+///#[derive(TypeLayout)]
+///#[repr(C)]
+///struct Header {
+///    inner: Padded,
+///}
+///```
+pub fn layout(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match assert::expand(args.into(), input) {
+        Ok(result) => result.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn add_static_bounds(generics: &syn::Generics) -> syn::Result<syn::Generics> {
+    if let Some(lifetime) = generics.lifetimes().next() {
+        return Err(syn::Error::new_spanned(lifetime, "type with lifetime parameters is not supported"));
+    }
+
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!('static));
+    }
+
+    Ok(generics)
+}
+
+fn struct_fields(input: &syn::DeriveInput) -> syn::Result<&syn::Fields> {
+    match &input.data {
+        syn::Data::Struct(data) => Ok(&data.fields),
+        syn::Data::Enum(_) => Err(syn::Error::new_spanned(&input.ident, "enum is not supported")),
+        syn::Data::Union(_) => Err(syn::Error::new_spanned(&input.ident, "union is not supported")),
+    }
+}
+
+fn field_member(idx: usize, field: &syn::Field) -> (String, proc_macro2::TokenStream) {
+    match &field.ident {
+        Some(ident) => (ident.to_string(), quote!(#ident)),
+        None => {
+            let member = syn::Index::from(idx);
+            (idx.to_string(), quote!(#member))
+        }
+    }
+}
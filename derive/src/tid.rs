@@ -0,0 +1,46 @@
+use quote::quote;
+
+pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut lifetimes = input.generics.lifetimes();
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (_, Some(second)) => return Err(syn::Error::new_spanned(second, "type with more than one lifetime is not supported")),
+        (Some(lifetime), None) => Some(lifetime.lifetime.clone()),
+        (None, None) => None,
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!('static));
+    }
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    let static_generics = generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(_) => quote!('static),
+        syn::GenericParam::Type(param) => {
+            let ident = &param.ident;
+            quote!(#ident)
+        },
+        syn::GenericParam::Const(param) => {
+            let ident = &param.ident;
+            quote!(#ident)
+        },
+    });
+
+    let mut tid_generics = generics.clone();
+    let lifetime = match lifetime {
+        Some(lifetime) => lifetime,
+        None => {
+            let lifetime: syn::Lifetime = syn::parse_quote!('__tid);
+            tid_generics.params.insert(0, syn::parse_quote!(#lifetime));
+            lifetime
+        },
+    };
+    let (impl_generics, _, _) = tid_generics.split_for_impl();
+    let name = &input.ident;
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::type_traits::Tid<#lifetime> for #name #ty_generics #where_clause {
+            type Static = #name<#(#static_generics),*>;
+        }
+    })
+}
@@ -0,0 +1,29 @@
+use type_traits::{layout, Type, TypeLayout};
+
+#[layout(size = 16, align = 8, no_padding, not_zst, no_drop)]
+#[derive(TypeLayout)]
+#[repr(C)]
+struct Header {
+    len: u64,
+    flags: u32,
+    kind: u32,
+}
+
+#[layout(size_at_most = 8, size_at_least = 1, align_at_least = 1, align_at_most = 8, niche, null_pointer_optimized)]
+struct Handle {
+    _ptr: core::ptr::NonNull<u8>,
+}
+
+#[layout(zst, size = 0)]
+struct Marker;
+
+#[layout()]
+enum Empty {}
+
+#[test]
+fn should_keep_type_definitions() {
+    assert_eq!(Type::<Header>::fields().len(), 3);
+    assert_eq!(Type::<Option<Handle>>::size(), Type::<*const u8>::size());
+    assert!(Type::<Marker>::is_zst());
+    assert!(Type::<Empty>::is_zst());
+}
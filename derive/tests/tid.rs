@@ -0,0 +1,85 @@
+use type_traits::{AnyBox, AnyMut, AnyRef, Tid};
+
+use core::cell::Cell;
+
+#[derive(Tid)]
+struct Context<'a> {
+    name: &'a str,
+    counter: &'a Cell<u32>,
+}
+
+#[derive(Tid)]
+struct Generic<'a, T, const N: usize> where T: Copy {
+    values: &'a [T; N],
+}
+
+#[derive(Tid, Debug)]
+struct Owned(u32);
+
+#[test]
+fn should_downcast_borrowed_context() {
+    let name = String::from("context");
+    let counter = Cell::new(0);
+    let context = Context {
+        name: &name,
+        counter: &counter,
+    };
+
+    let erased = AnyRef::new(&context);
+    assert!(erased.is::<Context>());
+    assert!(!erased.is::<Owned>());
+    let context = erased.downcast_ref::<Context>().unwrap();
+    context.counter.set(1);
+    assert_eq!(context.name, "context");
+    assert_eq!(counter.get(), 1);
+}
+
+#[test]
+fn should_distinguish_generic_arguments() {
+    let values = [1u8, 2];
+    let generic = Generic { values: &values };
+
+    let erased = AnyRef::new(&generic);
+    assert!(erased.downcast_ref::<Generic<u16, 2>>().is_none());
+    assert!(erased.downcast_ref::<Generic<u8, 3>>().is_none());
+    assert_eq!(erased.downcast_ref::<Generic<u8, 2>>().unwrap().values, &[1, 2]);
+}
+
+#[test]
+fn should_downcast_mutable_and_boxed() {
+    let mut owned = Owned(1);
+    let mut erased = AnyMut::new(&mut owned);
+    erased.downcast_mut::<Owned>().unwrap().0 += 1;
+    assert_eq!(owned.0, 2);
+
+    let name = String::from("boxed");
+    let counter = Cell::new(0);
+    let erased = AnyBox::new(Context {
+        name: &name,
+        counter: &counter,
+    });
+    let erased = erased.downcast::<Owned>().unwrap_err();
+    assert_eq!(erased.downcast_ref::<Context>().unwrap().name, "boxed");
+    let context = erased.downcast::<Context>().unwrap();
+    assert_eq!(context.name, "boxed");
+}
+
+#[test]
+fn should_drop_boxed_value() {
+    let counter = Cell::new(0);
+
+    struct Guard<'a>(&'a Cell<u32>);
+
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    unsafe impl<'a> Tid<'a> for Guard<'a> {
+        type Static = Guard<'static>;
+    }
+
+    drop(AnyBox::new(Guard(&counter)));
+    assert_eq!(counter.get(), 1);
+}
@@ -0,0 +1,121 @@
+use type_traits::{Type, TypeInfo, TypeLayout};
+
+#[derive(TypeLayout)]
+#[repr(C)]
+struct Ffi {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+#[derive(TypeLayout)]
+struct Tuple(u16, u64);
+
+#[derive(TypeLayout)]
+#[repr(C)]
+struct Generic<T> {
+    value: T,
+    flag: bool,
+    marker: core::marker::PhantomData<T>,
+}
+
+#[derive(TypeLayout)]
+struct Empty;
+
+#[derive(TypeLayout)]
+#[repr(C)]
+struct Inner {
+    a: u8,
+    b: u32,
+}
+
+#[derive(TypeLayout)]
+#[repr(C)]
+struct Outer {
+    inner: Inner,
+}
+
+#[derive(TypeLayout)]
+#[repr(C)]
+struct Nested {
+    outer: [Outer; 2],
+    ptr: &'static Inner,
+    len: u64,
+}
+
+#[test]
+fn should_describe_repr_c_struct() {
+    let fields = Type::<Ffi>::fields();
+    assert_eq!(fields.len(), 3);
+
+    assert_eq!(fields[0].name(), "a");
+    assert_eq!(*fields[0].info(), TypeInfo::of::<u8>());
+    assert_eq!(fields[0].offset(), 0);
+    assert_eq!(fields[0].size(), 1);
+    assert_eq!(fields[0].padding(), 3);
+
+    assert_eq!(fields[1].name(), "b");
+    assert_eq!(fields[1].offset(), 4);
+    assert_eq!(fields[1].size(), 4);
+    assert_eq!(fields[1].padding(), 0);
+
+    assert_eq!(fields[2].name(), "c");
+    assert_eq!(fields[2].offset(), 8);
+    assert_eq!(fields[2].size(), 2);
+    assert_eq!(fields[2].padding(), 2);
+}
+
+#[test]
+fn should_describe_tuple_struct() {
+    let fields = Type::<Tuple>::fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].name(), "0");
+    assert_eq!(fields[1].name(), "1");
+    assert_eq!(fields[0].offset(), core::mem::offset_of!(Tuple, 0));
+    assert_eq!(fields[1].offset(), core::mem::offset_of!(Tuple, 1));
+
+    let padding: usize = fields.iter().map(|field| field.padding()).sum();
+    assert_eq!(padding, Type::<Tuple>::size() - 2 - 8);
+}
+
+#[test]
+fn should_describe_generic_struct() {
+    let fields = <Generic<u32> as TypeLayout>::FIELDS;
+    assert_eq!(fields[0].info().size(), 4);
+    assert_eq!(fields[0].padding(), 0);
+    assert_eq!(fields[1].offset(), 4);
+    assert_eq!(fields[1].padding(), 3);
+    assert!(fields[2].info().is_zst());
+    assert_eq!(fields[2].padding(), 0);
+
+    let fields = Type::<Generic<u8>>::fields();
+    assert_eq!(fields[0].padding(), 0);
+    assert_eq!(fields[1].padding(), 0);
+}
+
+#[test]
+fn should_describe_empty_struct() {
+    assert!(Type::<Empty>::fields().is_empty());
+}
+
+#[test]
+fn should_count_padding_bytes() {
+    const FFI_PADDING: usize = Type::<Ffi>::padding_bytes();
+    assert_eq!(FFI_PADDING, 5);
+    assert_eq!(Type::<Generic<u8>>::padding_bytes(), 0);
+    assert_eq!(Type::<Generic<u32>>::padding_bytes(), 3);
+    assert_eq!(Type::<Empty>::padding_bytes(), 0);
+    assert_eq!(Type::<u64>::padding_bytes(), 0);
+}
+
+#[test]
+fn should_count_padding_within_nested_fields() {
+    assert_eq!(Type::<Inner>::padding_bytes(), 3);
+    assert_eq!(Type::<Outer>::padding_bytes(), 3);
+    assert_eq!(Type::<[Outer; 2]>::padding_bytes(), 6);
+    assert_eq!(Type::<Nested>::padding_bytes(), 6);
+
+    let fields = Type::<Outer>::fields();
+    assert_eq!(fields[0].padding(), 0);
+    assert_eq!(fields[0].inner_padding(), 3);
+}
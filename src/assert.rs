@@ -0,0 +1,136 @@
+//!Static assertions parameterised by const generics
+
+use crate::Type;
+use crate::msg::Message;
+
+use core::marker;
+
+macro_rules! declare_assert {
+    ($(#[$doc:meta])* $name:ident: $property:literal $actual:expr, $op:tt $relation:literal) => {
+        $(#[$doc])*
+        ///
+        ///This assertion relies on the fact that generic code is always compiled when generic is actually
+        ///used, hence in order to perform assertion, you must use associated constant `ASSERT`.
+        #[repr(transparent)]
+        pub struct $name<T, const N: usize>(marker::PhantomData<T>);
+
+        impl<T, const N: usize> $name<T, N> {
+            ///Performs assertion
+            pub const ASSERT: () = if !($actual $op N) {
+                Message::new().value($property, $actual).text($relation).num(N).fail()
+            };
+        }
+    };
+}
+
+declare_assert!(
+    ///Asserts type size is exactly `N`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::SizeIs;
+    ///
+    ///fn test<T>(input: T) {
+    ///    let _ = SizeIs::<T, 4>::ASSERT;
+    ///}
+    ///
+    ///test(0u32);
+    ///```
+    SizeIs: "size_of::<T>" Type::<T>::size(), == " must equal "
+);
+
+declare_assert!(
+    ///Asserts type size is less or equal to `N`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::SizeAtMost;
+    ///
+    ///fn send<T>(payload: T) {
+    ///    let _ = SizeAtMost::<T, 64>::ASSERT;
+    ///}
+    ///
+    ///send([0u8; 64]);
+    ///```
+    ///
+    ///```compile_fail
+    ///use type_traits::SizeAtMost;
+    ///
+    ///fn send<T>(payload: T) {
+    ///    let _ = SizeAtMost::<T, 64>::ASSERT;
+    ///}
+    ///
+    ///send([0u8; 65]);
+    ///```
+    SizeAtMost: "size_of::<T>" Type::<T>::size(), <= " must be less or equal to "
+);
+
+declare_assert!(
+    ///Asserts type size is greater or equal to `N`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::SizeAtLeast;
+    ///
+    ///fn test<T>(input: T) {
+    ///    let _ = SizeAtLeast::<T, 2>::ASSERT;
+    ///}
+    ///
+    ///test(0u16);
+    ///```
+    SizeAtLeast: "size_of::<T>" Type::<T>::size(), >= " must be greater or equal to "
+);
+
+declare_assert!(
+    ///Asserts type minimum alignment is exactly `N`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::AlignIs;
+    ///
+    ///fn test<T>(input: T) {
+    ///    let _ = AlignIs::<T, 2>::ASSERT;
+    ///}
+    ///
+    ///test(0u16);
+    ///```
+    AlignIs: "align_of::<T>" Type::<T>::align(), == " must equal "
+);
+
+declare_assert!(
+    ///Asserts type minimum alignment is greater or equal to `N`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::AlignAtLeast;
+    ///
+    ///fn test<T>(input: T) {
+    ///    let _ = AlignAtLeast::<T, 2>::ASSERT;
+    ///}
+    ///
+    ///test(0u32);
+    ///```
+    AlignAtLeast: "align_of::<T>" Type::<T>::align(), >= " must be greater or equal to "
+);
+
+declare_assert!(
+    ///Asserts type minimum alignment is less or equal to `N`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::AlignAtMost;
+    ///
+    ///fn test<T>(input: T) {
+    ///    let _ = AlignAtMost::<T, 2>::ASSERT;
+    ///}
+    ///
+    ///test(0u8);
+    ///```
+    AlignAtMost: "align_of::<T>" Type::<T>::align(), <= " must be less or equal to "
+);
@@ -0,0 +1,312 @@
+//!Reinterpret casts verified by static assertions
+
+use crate::{Type, Assert, Assert2};
+
+use core::{fmt, mem, slice};
+
+///Reinterprets `L` as `R`, verifying both types are of the same size at compile time.
+///
+///## Safety
+///
+///Every bit pattern of `L` must be valid for `R`, with the same requirements as for
+///[transmute](https://doc.rust-lang.org/core/mem/fn.transmute.html)
+///
+///## Usage
+///
+///```
+///use type_traits::transmute_checked;
+///
+///fn to_bits<T, B>(value: T) -> B {
+///    unsafe {
+///        transmute_checked(value)
+///    }
+///}
+///
+///assert_eq!(to_bits::<f32, u32>(1.0), 1.0f32.to_bits());
+///```
+///
+///```compile_fail
+///use type_traits::transmute_checked;
+///
+///let _: u64 = unsafe { transmute_checked(0u32) };
+///```
+#[inline(always)]
+pub unsafe fn transmute_checked<L, R>(value: L) -> R {
+    let _ = Assert2::<L, R>::IS_SAME_SIZE;
+
+    let value = mem::ManuallyDrop::new(value);
+    mem::transmute_copy(&*value)
+}
+
+///Reinterprets reference to `L` as reference to `R`, verifying at compile time that both types
+///are of the same size and `L` alignment is sufficient for `R`.
+///
+///## Safety
+///
+///Every bit pattern of `L` must be valid for `R`.
+///
+///`L` must contain no padding or otherwise uninitialised bytes, as these would be read through
+///`R` (e.g. `&(u8, u16)` as `&[u8; 4]`). Use
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) to verify it at
+///compile time when `L` implements [TypeLayout](trait.TypeLayout.html).
+///
+///## Usage
+///
+///```
+///use type_traits::transmute_ref_checked;
+///
+///let value = u32::from_ne_bytes([1, 2, 3, 4]);
+///let bytes: &[u8; 4] = unsafe { transmute_ref_checked(&value) };
+///assert_eq!(*bytes, [1, 2, 3, 4]);
+///```
+///
+///```compile_fail
+///use type_traits::transmute_ref_checked;
+///
+///let _: &u32 = unsafe { transmute_ref_checked(&[0u8; 4]) };
+///```
+#[inline(always)]
+pub unsafe fn transmute_ref_checked<L, R>(value: &L) -> &R {
+    &*ptr_cast_checked(value)
+}
+
+///Reinterprets mutable reference to `L` as mutable reference to `R`, verifying at compile time
+///that both types are of the same size and `L` alignment is sufficient for `R`.
+///
+///## Safety
+///
+///Every bit pattern of `L` must be valid for `R` and vice versa.
+///
+///Neither `L` nor `R` may contain padding or otherwise uninitialised bytes, as bytes written
+///through one type are read through the other. Use
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) to verify it at
+///compile time when types implement [TypeLayout](trait.TypeLayout.html).
+///
+///## Usage
+///
+///```
+///use type_traits::transmute_mut_checked;
+///
+///let mut value = 0u32;
+///let bytes: &mut [u8; 4] = unsafe { transmute_mut_checked(&mut value) };
+///bytes[0] = 1;
+///assert_eq!(value, u32::from_ne_bytes([1, 0, 0, 0]));
+///```
+#[inline(always)]
+pub unsafe fn transmute_mut_checked<L, R>(value: &mut L) -> &mut R {
+    &mut *ptr_cast_mut_checked(value)
+}
+
+///Casts pointer to `L` into pointer to `R`, verifying at compile time that both types are of the
+///same size and `L` alignment is sufficient for `R`.
+///
+///## Usage
+///
+///```
+///use type_traits::ptr_cast_checked;
+///
+///let value = 1.0f64;
+///let bits = ptr_cast_checked::<f64, u64>(&value);
+///assert_eq!(unsafe { *bits }, value.to_bits());
+///```
+#[inline(always)]
+pub const fn ptr_cast_checked<L, R>(ptr: *const L) -> *const R {
+    let _ = Assert2::<L, R>::IS_SAME_SIZE;
+    let _ = Assert2::<L, R>::IS_LEFT_ALIGN_GREATER_OR_EQUAL;
+
+    ptr as *const R
+}
+
+///Casts mutable pointer to `L` into mutable pointer to `R`, verifying at compile time that both
+///types are of the same size and `L` alignment is sufficient for `R`.
+///
+///## Usage
+///
+///```
+///use type_traits::ptr_cast_mut_checked;
+///
+///let mut value = 0i32;
+///let ptr = ptr_cast_mut_checked::<i32, u32>(&mut value);
+///unsafe {
+///    *ptr = u32::MAX;
+///}
+///assert_eq!(value, -1);
+///```
+#[inline(always)]
+pub const fn ptr_cast_mut_checked<L, R>(ptr: *mut L) -> *mut R {
+    let _ = Assert2::<L, R>::IS_SAME_SIZE;
+    let _ = Assert2::<L, R>::IS_LEFT_ALIGN_GREATER_OR_EQUAL;
+
+    ptr as *mut R
+}
+
+///Error of slice cast
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliceCastError {
+    ///One of the types is ZST
+    Zst,
+    ///Slice size in bytes is not multiple of target type size
+    Size,
+    ///Slice pointer is not aligned for target type
+    Align,
+}
+
+impl fmt::Display for SliceCastError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceCastError::Zst => fmt.write_str("cannot cast slice of zero sized type"),
+            SliceCastError::Size => fmt.write_str("slice size is not multiple of target type size"),
+            SliceCastError::Align => fmt.write_str("slice is not aligned for target type"),
+        }
+    }
+}
+
+impl core::error::Error for SliceCastError {}
+
+#[inline(always)]
+const fn cast_slice_len<A, B>(len: usize) -> usize {
+    let _ = Assert::<A>::IS_NOT_ZST;
+    let _ = Assert2::<A, B>::IS_LEFT_SIZE_MULTIPLE;
+    let _ = Assert2::<A, B>::IS_LEFT_ALIGN_GREATER_OR_EQUAL;
+
+    len * (Type::<A>::size() / Type::<B>::size())
+}
+
+#[inline]
+fn try_cast_slice_len<A, B>(ptr: *const A, len: usize) -> Result<usize, SliceCastError> {
+    if Type::<A>::is_zst() || Type::<B>::is_zst() {
+        Err(SliceCastError::Zst)
+    } else if !(len * Type::<A>::size()).is_multiple_of(Type::<B>::size()) {
+        Err(SliceCastError::Size)
+    } else if !(ptr as *const B).is_aligned() {
+        Err(SliceCastError::Align)
+    } else {
+        Ok((len * Type::<A>::size()) / Type::<B>::size())
+    }
+}
+
+///Reinterprets slice of `A` as slice of `B`.
+///
+///Verifies at compile time that types are not ZST, size of `A` is multiple of `B` size and `A`
+///alignment is sufficient for `B`.
+///
+///## Safety
+///
+///Every bit pattern of `A` must be valid for `B`.
+///
+///`A` must contain no padding or otherwise uninitialised bytes, as these would be read through
+///`B` (e.g. casting `[(u8, u16)]` to `[u8]` reads padding byte of every tuple). Use
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) to verify it at
+///compile time when `A` implements [TypeLayout](trait.TypeLayout.html).
+///
+///## Usage
+///
+///```
+///use type_traits::cast_slice;
+///
+///let words = [u32::from_ne_bytes([1, 2, 3, 4]), u32::from_ne_bytes([5, 6, 7, 8])];
+///let bytes: &[u8] = unsafe { cast_slice(&words) };
+///assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8]);
+///```
+///
+///```compile_fail
+///use type_traits::cast_slice;
+///
+///let _: &[u32] = unsafe { cast_slice(&[0u8; 4]) };
+///```
+#[inline(always)]
+pub unsafe fn cast_slice<A, B>(slice: &[A]) -> &[B] {
+    let len = cast_slice_len::<A, B>(slice.len());
+    slice::from_raw_parts(slice.as_ptr() as *const B, len)
+}
+
+///Reinterprets mutable slice of `A` as mutable slice of `B`.
+///
+///Verifies at compile time that types are not ZST, size of `A` is multiple of `B` size and `A`
+///alignment is sufficient for `B`.
+///
+///## Safety
+///
+///Every bit pattern of `A` must be valid for `B` and vice versa.
+///
+///Neither `A` nor `B` may contain padding or otherwise uninitialised bytes, as bytes written
+///through one type are read through the other. Use
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) to verify it at
+///compile time when types implement [TypeLayout](trait.TypeLayout.html).
+///
+///## Usage
+///
+///```
+///use type_traits::cast_slice_mut;
+///
+///let mut words = [0u16; 2];
+///let bytes: &mut [u8] = unsafe { cast_slice_mut(&mut words) };
+///bytes.copy_from_slice(&[1, 2, 3, 4]);
+///assert_eq!(words, [u16::from_ne_bytes([1, 2]), u16::from_ne_bytes([3, 4])]);
+///```
+#[inline(always)]
+pub unsafe fn cast_slice_mut<A, B>(slice: &mut [A]) -> &mut [B] {
+    let len = cast_slice_len::<A, B>(slice.len());
+    slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut B, len)
+}
+
+///Reinterprets slice of `A` as slice of `B`, verifying size and alignment at runtime.
+///
+///## Safety
+///
+///Every bit pattern of `A` must be valid for `B`.
+///
+///`A` must contain no padding or otherwise uninitialised bytes, as these would be read through
+///`B` (e.g. casting `[(u8, u16)]` to `[u8]` reads padding byte of every tuple). Use
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) to verify it at
+///compile time when `A` implements [TypeLayout](trait.TypeLayout.html).
+///
+///## Usage
+///
+///```
+///use type_traits::{try_cast_slice, SliceCastError};
+///
+///let words = [0u32; 2];
+///let bytes: &[u8] = unsafe { try_cast_slice(&words).unwrap() };
+///let halfs: &[u16] = unsafe { try_cast_slice(bytes).unwrap() };
+///assert_eq!(halfs, [0u16; 4]);
+///
+///let error = unsafe { try_cast_slice::<u8, u32>(&bytes[..3]) };
+///assert_eq!(error, Err(SliceCastError::Size));
+///let error = unsafe { try_cast_slice::<u8, u32>(&bytes[1..5]) };
+///assert_eq!(error, Err(SliceCastError::Align));
+///```
+#[inline]
+pub unsafe fn try_cast_slice<A, B>(slice: &[A]) -> Result<&[B], SliceCastError> {
+    let len = try_cast_slice_len::<A, B>(slice.as_ptr(), slice.len())?;
+    Ok(slice::from_raw_parts(slice.as_ptr() as *const B, len))
+}
+
+///Reinterprets mutable slice of `A` as mutable slice of `B`, verifying size and alignment at runtime.
+///
+///## Safety
+///
+///Every bit pattern of `A` must be valid for `B` and vice versa.
+///
+///Neither `A` nor `B` may contain padding or otherwise uninitialised bytes, as bytes written
+///through one type are read through the other. Use
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) to verify it at
+///compile time when types implement [TypeLayout](trait.TypeLayout.html).
+///
+///## Usage
+///
+///```
+///use type_traits::try_cast_slice_mut;
+///
+///let mut words = [0u32; 1];
+///let bytes: &mut [u8] = unsafe { try_cast_slice_mut(&mut words).unwrap() };
+///let halfs: &mut [u16] = unsafe { try_cast_slice_mut(bytes).unwrap() };
+///halfs[0] = u16::MAX;
+///halfs[1] = u16::MAX;
+///assert_eq!(words, [u32::MAX]);
+///```
+#[inline]
+pub unsafe fn try_cast_slice_mut<A, B>(slice: &mut [A]) -> Result<&mut [B], SliceCastError> {
+    let len = try_cast_slice_len::<A, B>(slice.as_ptr(), slice.len())?;
+    Ok(slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut B, len))
+}
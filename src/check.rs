@@ -0,0 +1,402 @@
+//!Runtime counterparts of static assertions
+
+use crate::{Type, TypeInfo, TypeLayout, TypeList};
+
+use core::{fmt, marker};
+
+///Relation, that value is required to satisfy
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Relation {
+    ///Value must be equal to expected
+    Equal,
+    ///Value must not be equal to expected
+    NotEqual,
+    ///Value must be greater or equal to expected
+    AtLeast,
+    ///Value must be less or equal to expected
+    AtMost,
+    ///Value must be less than expected
+    Less,
+    ///Value must be multiple of expected, which is never satisfied by `0`
+    MultipleOf,
+}
+
+impl Relation {
+    #[inline]
+    ///Returns whether `actual` satisfies relation to `expected`
+    pub const fn is_satisfied(&self, actual: usize, expected: usize) -> bool {
+        match self {
+            Relation::Equal => actual == expected,
+            Relation::NotEqual => actual != expected,
+            Relation::AtLeast => actual >= expected,
+            Relation::AtMost => actual <= expected,
+            Relation::Less => actual < expected,
+            Relation::MultipleOf => expected != 0 && actual.is_multiple_of(expected),
+        }
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Relation::Equal => fmt.write_str("equal"),
+            Relation::NotEqual => fmt.write_str("not equal"),
+            Relation::AtLeast => fmt.write_str("be greater or equal to"),
+            Relation::AtMost => fmt.write_str("be less or equal to"),
+            Relation::Less => fmt.write_str("be less than"),
+            Relation::MultipleOf => fmt.write_str("be multiple of non-zero"),
+        }
+    }
+}
+
+///Error of runtime layout check
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LayoutMismatch {
+    ///Size does not satisfy relation to expected value
+    Size {
+        ///Actual size
+        actual: usize,
+        ///Required relation
+        relation: Relation,
+        ///Expected size
+        expected: usize,
+    },
+    ///Minimum alignment does not satisfy relation to expected value
+    Align {
+        ///Actual alignment
+        actual: usize,
+        ///Required relation
+        relation: Relation,
+        ///Expected alignment
+        expected: usize,
+    },
+    ///Size of list's element does not satisfy relation to expected value
+    ElementSize {
+        ///Index of element within list
+        index: usize,
+        ///Actual size
+        actual: usize,
+        ///Required relation
+        relation: Relation,
+        ///Expected size
+        expected: usize,
+    },
+    ///Minimum alignment of list's element does not satisfy relation to expected value
+    ElementAlign {
+        ///Index of element within list
+        index: usize,
+        ///Actual alignment
+        actual: usize,
+        ///Required relation
+        relation: Relation,
+        ///Expected alignment
+        expected: usize,
+    },
+    ///Type requires call to `Drop::drop`
+    NeedsDrop,
+    ///Type has no niche, i.e. `Option` of type is bigger than type
+    Niche {
+        ///Actual size of `Option`
+        actual: usize,
+        ///Expected size of `Option`, which is size of type
+        expected: usize,
+    },
+    ///Type has padding between its fields
+    Padding {
+        ///Actual number of padding bytes
+        actual: usize,
+    },
+    ///Type is not the same as expected
+    Type {
+        ///Actual type name
+        actual: &'static str,
+        ///Expected type name
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for LayoutMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutMismatch::Size { actual, relation, expected } => write!(fmt, "size ({}) must {} {}", actual, relation, expected),
+            LayoutMismatch::Align { actual, relation, expected } => write!(fmt, "alignment ({}) must {} {}", actual, relation, expected),
+            LayoutMismatch::ElementSize { index, actual, relation, expected } => write!(fmt, "size of element {} ({}) must {} {}", index, actual, relation, expected),
+            LayoutMismatch::ElementAlign { index, actual, relation, expected } => write!(fmt, "alignment of element {} ({}) must {} {}", index, actual, relation, expected),
+            LayoutMismatch::NeedsDrop => fmt.write_str("type must not need drop"),
+            LayoutMismatch::Niche { actual, expected } => write!(fmt, "size of Option ({}) must equal size of type ({})", actual, expected),
+            LayoutMismatch::Padding { actual } => write!(fmt, "padding bytes ({}) must be 0", actual),
+            LayoutMismatch::Type { actual, expected } => write!(fmt, "type {} must be {}", actual, expected),
+        }
+    }
+}
+
+impl core::error::Error for LayoutMismatch {}
+
+#[inline]
+pub(crate) const fn no_need_drop(needs_drop: bool) -> Result<(), LayoutMismatch> {
+    match needs_drop {
+        true => Err(LayoutMismatch::NeedsDrop),
+        false => Ok(()),
+    }
+}
+
+#[inline]
+pub(crate) const fn size(actual: usize, relation: Relation, expected: usize) -> Result<(), LayoutMismatch> {
+    match relation.is_satisfied(actual, expected) {
+        true => Ok(()),
+        false => Err(LayoutMismatch::Size { actual, relation, expected }),
+    }
+}
+
+#[inline]
+pub(crate) const fn align(actual: usize, relation: Relation, expected: usize) -> Result<(), LayoutMismatch> {
+    match relation.is_satisfied(actual, expected) {
+        true => Ok(()),
+        false => Err(LayoutMismatch::Align { actual, relation, expected }),
+    }
+}
+
+#[inline]
+const fn element_size(index: usize, actual: usize, relation: Relation, expected: usize) -> Result<(), LayoutMismatch> {
+    match relation.is_satisfied(actual, expected) {
+        true => Ok(()),
+        false => Err(LayoutMismatch::ElementSize { index, actual, relation, expected }),
+    }
+}
+
+#[inline]
+const fn element_align(index: usize, actual: usize, relation: Relation, expected: usize) -> Result<(), LayoutMismatch> {
+    match relation.is_satisfied(actual, expected) {
+        true => Ok(()),
+        false => Err(LayoutMismatch::ElementAlign { index, actual, relation, expected }),
+    }
+}
+
+///Runtime check helper for type
+///
+///Mirrors every assertion of [Assert](struct.Assert.html) together with
+///[SizeIs](struct.SizeIs.html) and its siblings, but reports failure as [LayoutMismatch](enum.LayoutMismatch.html)
+///instead of failing build.
+///
+///Type, known only at runtime, can be checked via its [TypeInfo](struct.TypeInfo.html) instead.
+///
+///## Usage
+///
+///```
+///use type_traits::{Check, LayoutMismatch, Relation};
+///
+///assert_eq!(Check::<u32>::no_need_drop(), Ok(()));
+///assert_eq!(Check::<String>::no_need_drop(), Err(LayoutMismatch::NeedsDrop));
+///assert_eq!(Check::<u32>::size_at_most(2), Err(LayoutMismatch::Size { actual: 4, relation: Relation::AtMost, expected: 2 }));
+///```
+pub struct Check<T>(marker::PhantomData<T>);
+
+impl<T> Check<T> {
+    #[inline]
+    ///Checks type requires no call `Drop::drop`, same as [Assert::NO_NEED_DROP](struct.Assert.html#associatedconstant.NO_NEED_DROP)
+    pub const fn no_need_drop() -> Result<(), LayoutMismatch> {
+        no_need_drop(Type::<T>::needs_drop())
+    }
+
+    #[inline]
+    ///Checks type is not ZST, same as [Assert::IS_NOT_ZST](struct.Assert.html#associatedconstant.IS_NOT_ZST)
+    pub const fn is_not_zst() -> Result<(), LayoutMismatch> {
+        size(Type::<T>::size(), Relation::NotEqual, 0)
+    }
+
+    #[inline]
+    ///Checks type is ZST, same as [Assert::IS_ZST](struct.Assert.html#associatedconstant.IS_ZST)
+    pub const fn is_zst() -> Result<(), LayoutMismatch> {
+        size(Type::<T>::size(), Relation::Equal, 0)
+    }
+
+    #[inline]
+    ///Checks type has niche, same as [Assert::HAS_NICHE](struct.Assert.html#associatedconstant.HAS_NICHE)
+    pub const fn has_niche() -> Result<(), LayoutMismatch> {
+        match Type::<T>::has_niche() {
+            true => Ok(()),
+            false => Err(LayoutMismatch::Niche {
+                actual: Type::<Option<T>>::size(),
+                expected: Type::<T>::size(),
+            }),
+        }
+    }
+
+    #[inline]
+    ///Checks `Option<T>` is pointer sized, same as [Assert::IS_NULL_POINTER_OPTIMIZED](struct.Assert.html#associatedconstant.IS_NULL_POINTER_OPTIMIZED)
+    pub const fn is_null_pointer_optimized() -> Result<(), LayoutMismatch> {
+        match size(Type::<T>::size(), Relation::Equal, Type::<*const ()>::size()) {
+            Ok(()) => Self::has_niche(),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[inline]
+    ///Checks type size is exactly `expected`, same as [SizeIs](struct.SizeIs.html)
+    pub const fn size_is(expected: usize) -> Result<(), LayoutMismatch> {
+        size(Type::<T>::size(), Relation::Equal, expected)
+    }
+
+    #[inline]
+    ///Checks type size is less or equal to `expected`, same as [SizeAtMost](struct.SizeAtMost.html)
+    pub const fn size_at_most(expected: usize) -> Result<(), LayoutMismatch> {
+        size(Type::<T>::size(), Relation::AtMost, expected)
+    }
+
+    #[inline]
+    ///Checks type size is greater or equal to `expected`, same as [SizeAtLeast](struct.SizeAtLeast.html)
+    pub const fn size_at_least(expected: usize) -> Result<(), LayoutMismatch> {
+        size(Type::<T>::size(), Relation::AtLeast, expected)
+    }
+
+    #[inline]
+    ///Checks type minimum alignment is exactly `expected`, same as [AlignIs](struct.AlignIs.html)
+    pub const fn align_is(expected: usize) -> Result<(), LayoutMismatch> {
+        align(Type::<T>::align(), Relation::Equal, expected)
+    }
+
+    #[inline]
+    ///Checks type minimum alignment is greater or equal to `expected`, same as [AlignAtLeast](struct.AlignAtLeast.html)
+    pub const fn align_at_least(expected: usize) -> Result<(), LayoutMismatch> {
+        align(Type::<T>::align(), Relation::AtLeast, expected)
+    }
+
+    #[inline]
+    ///Checks type minimum alignment is less or equal to `expected`, same as [AlignAtMost](struct.AlignAtMost.html)
+    pub const fn align_at_most(expected: usize) -> Result<(), LayoutMismatch> {
+        align(Type::<T>::align(), Relation::AtMost, expected)
+    }
+}
+
+impl<T: TypeLayout> Check<T> {
+    #[inline]
+    ///Checks struct has no padding between its fields, same as [Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING)
+    pub const fn has_no_padding() -> Result<(), LayoutMismatch> {
+        match Type::<T>::padding_bytes() {
+            0 => Ok(()),
+            actual => Err(LayoutMismatch::Padding { actual }),
+        }
+    }
+}
+
+impl<T: TypeList> Check<T> {
+    #[inline]
+    ///Checks all types within list are of the same size, same as [Assert::ALL_SAME_SIZE](struct.Assert.html#associatedconstant.ALL_SAME_SIZE)
+    ///
+    ///Error is reported for first element, that differs from the first one, alongside with its index.
+    pub const fn all_same_size() -> Result<(), LayoutMismatch> {
+        let mut idx = 1;
+        while idx < T::LEN {
+            if let Err(error) = element_size(idx, T::SIZES[idx], Relation::Equal, T::SIZES[0]) {
+                return Err(error);
+            }
+            idx += 1;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    ///Checks all types within list are of the same minimum alignment, same as [Assert::ALL_SAME_ALIGN](struct.Assert.html#associatedconstant.ALL_SAME_ALIGN)
+    ///
+    ///Error is reported for first element, that differs from the first one, alongside with its index.
+    pub const fn all_same_align() -> Result<(), LayoutMismatch> {
+        let mut idx = 1;
+        while idx < T::LEN {
+            if let Err(error) = element_align(idx, T::ALIGNS[idx], Relation::Equal, T::ALIGNS[0]) {
+                return Err(error);
+            }
+            idx += 1;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    ///Checks none of types within list requires call to `Drop::drop`, same as [Assert::NONE_NEED_DROP](struct.Assert.html#associatedconstant.NONE_NEED_DROP)
+    pub const fn none_need_drop() -> Result<(), LayoutMismatch> {
+        no_need_drop(T::ANY_NEEDS_DROP)
+    }
+
+    #[inline]
+    ///Checks every type within list has size less or equal to `expected`, same as [AllFitIn](struct.AllFitIn.html)
+    ///
+    ///Error is reported for first element, that exceeds `expected`, alongside with its index.
+    pub const fn all_fit_in(expected: usize) -> Result<(), LayoutMismatch> {
+        let mut idx = 0;
+        while idx < T::LEN {
+            if let Err(error) = element_size(idx, T::SIZES[idx], Relation::AtMost, expected) {
+                return Err(error);
+            }
+            idx += 1;
+        }
+        Ok(())
+    }
+}
+
+///Runtime check helper for pair of types
+///
+///Mirrors every assertion of [Assert2](struct.Assert2.html), but reports failure as [LayoutMismatch](enum.LayoutMismatch.html)
+///instead of failing build.
+///
+///## Usage
+///
+///```
+///use type_traits::{Check2, LayoutMismatch, Relation};
+///
+///assert_eq!(Check2::<u32, f32>::is_same_size(), Ok(()));
+///assert_eq!(Check2::<u64, u32>::is_same_size(), Err(LayoutMismatch::Size { actual: 8, relation: Relation::Equal, expected: 4 }));
+///```
+pub struct Check2<L, R>(marker::PhantomData<(L, R)>);
+
+impl<L, R> Check2<L, R> {
+    #[inline]
+    ///Checks both types are of the same size, same as [Assert2::IS_SAME_SIZE](struct.Assert2.html#associatedconstant.IS_SAME_SIZE)
+    pub const fn is_same_size() -> Result<(), LayoutMismatch> {
+        size(Type::<L>::size(), Relation::Equal, Type::<R>::size())
+    }
+
+    #[inline]
+    ///Checks both types are of the same minimum alignment, same as [Assert2::IS_SAME_ALIGN](struct.Assert2.html#associatedconstant.IS_SAME_ALIGN)
+    pub const fn is_same_align() -> Result<(), LayoutMismatch> {
+        align(Type::<L>::align(), Relation::Equal, Type::<R>::align())
+    }
+
+    #[inline]
+    ///Checks `L` size is greater or equal to `R`, same as [Assert2::IS_LEFT_SIZE_GREATER_OR_EQUAL](struct.Assert2.html#associatedconstant.IS_LEFT_SIZE_GREATER_OR_EQUAL)
+    pub const fn is_left_size_greater_or_equal() -> Result<(), LayoutMismatch> {
+        size(Type::<L>::size(), Relation::AtLeast, Type::<R>::size())
+    }
+
+    #[inline]
+    ///Checks `L` size is less than `R`, same as [Assert2::IS_LEFT_SIZE_LESS](struct.Assert2.html#associatedconstant.IS_LEFT_SIZE_LESS)
+    pub const fn is_left_size_less() -> Result<(), LayoutMismatch> {
+        size(Type::<L>::size(), Relation::Less, Type::<R>::size())
+    }
+
+    #[inline]
+    ///Checks `L` size is multiple of non-zero `R` size, same as [Assert2::IS_LEFT_SIZE_MULTIPLE](struct.Assert2.html#associatedconstant.IS_LEFT_SIZE_MULTIPLE)
+    pub const fn is_left_size_multiple() -> Result<(), LayoutMismatch> {
+        size(Type::<L>::size(), Relation::MultipleOf, Type::<R>::size())
+    }
+
+    #[inline]
+    ///Checks `L` minimum alignment is greater or equal to `R`, same as [Assert2::IS_LEFT_ALIGN_GREATER_OR_EQUAL](struct.Assert2.html#associatedconstant.IS_LEFT_ALIGN_GREATER_OR_EQUAL)
+    pub const fn is_left_align_greater_or_equal() -> Result<(), LayoutMismatch> {
+        align(Type::<L>::align(), Relation::AtLeast, Type::<R>::align())
+    }
+
+    #[inline]
+    ///Checks `L` minimum alignment is less than `R`, same as [Assert2::IS_LEFT_ALIGN_LESS](struct.Assert2.html#associatedconstant.IS_LEFT_ALIGN_LESS)
+    pub const fn is_left_align_less() -> Result<(), LayoutMismatch> {
+        align(Type::<L>::align(), Relation::Less, Type::<R>::align())
+    }
+}
+
+impl<L: 'static, R: 'static> Check2<L, R> {
+    #[inline]
+    ///Checks both types are the same, same as [Assert2::IS_SAME_TYPE](struct.Assert2.html#associatedconstant.IS_SAME_TYPE)
+    ///
+    ///Unlike assertion, it is available in generic code.
+    pub fn is_same_type() -> Result<(), LayoutMismatch> {
+        TypeInfo::of::<L>().check_type(&TypeInfo::of::<R>())
+    }
+}
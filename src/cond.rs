@@ -0,0 +1,129 @@
+//!Type level booleans
+
+use crate::Type;
+
+///Type level boolean
+///
+///For concrete type any constant property can be turned into type level boolean.
+///
+///```
+///use type_traits::{Type, Bool, Boolean};
+///
+///struct Payload([u8; 48]);
+///
+///type FitsInCacheLine = Bool<{ Type::<Payload>::size() <= 64 }>;
+///assert!(FitsInCacheLine::VALUE);
+///```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bool<const B: bool>;
+
+///Type level `true`
+pub type True = Bool<true>;
+///Type level `false`
+pub type False = Bool<false>;
+
+///Type level boolean operations
+pub trait Boolean {
+    ///Value of boolean
+    const VALUE: bool;
+
+    ///Negation
+    type Not: Boolean;
+    ///Conjunction
+    type And<O: Boolean>: Boolean;
+    ///Disjunction
+    type Or<O: Boolean>: Boolean;
+    ///Selects `Then` if true, otherwise `Else`
+    type Select<Then, Else>;
+}
+
+impl Boolean for Bool<true> {
+    const VALUE: bool = true;
+
+    type Not = False;
+    type And<O: Boolean> = O;
+    type Or<O: Boolean> = True;
+    type Select<Then, Else> = Then;
+}
+
+impl Boolean for Bool<false> {
+    const VALUE: bool = false;
+
+    type Not = True;
+    type And<O: Boolean> = False;
+    type Or<O: Boolean> = O;
+    type Select<Then, Else> = Else;
+}
+
+///Selects `Then` if `Cond` is true, otherwise `Else`
+///
+///```
+///use type_traits::{If, True, False, Type};
+///
+///assert!(Type::<If<True, u8, u16>>::is::<u8>());
+///assert!(Type::<If<False, u8, u16>>::is::<u16>());
+///```
+pub type If<Cond, Then, Else> = <Cond as Boolean>::Select<Then, Else>;
+
+///Properties of type as type level booleans.
+///
+///Stable Rust cannot compute constant expression of generic parameter at type level, hence it is
+///implemented only for concrete types: primitives and types passed to [impl_type_facts](macro.impl_type_facts.html).
+///Generic code should require `TypeFacts` bound to make decisions at type level.
+///
+///## Usage
+///
+///```
+///use type_traits::{impl_type_facts, If, Type, TypeFacts};
+///
+///struct Marker;
+///struct Small(u32);
+///struct Large([u64; 8]);
+///impl_type_facts!(Marker, Small, Large);
+///
+///struct Unit;
+///struct Inline<T>(T);
+///struct Boxed<T>(Box<T>);
+///
+///type Storage<T> = If<<T as TypeFacts>::IsZst, Unit, If<<T as TypeFacts>::FitsInPointer, Inline<T>, Boxed<T>>>;
+///
+///assert!(Type::<Storage<Marker>>::is::<Unit>());
+///assert!(Type::<Storage<Small>>::is::<Inline<Small>>());
+///assert!(Type::<Storage<Large>>::is::<Boxed<Large>>());
+///```
+pub trait TypeFacts {
+    ///Whether type is ZST
+    type IsZst: Boolean;
+    ///Whether type has `Drop` implementation with side effects
+    type NeedsDrop: Boolean;
+    ///Whether `Option` of type is of the same size
+    type HasNiche: Boolean;
+    ///Whether type's size and alignment do not exceed that of pointer
+    type FitsInPointer: Boolean;
+}
+
+#[doc(hidden)]
+pub const fn fits_in_pointer<T>() -> bool {
+    Type::<T>::size() <= Type::<*const ()>::size() && Type::<T>::align() <= Type::<*const ()>::align()
+}
+
+///Implements [TypeFacts](trait.TypeFacts.html) for concrete types
+#[macro_export]
+macro_rules! impl_type_facts {
+    ($($typ:ty),+ $(,)?) => {
+        $(
+            impl $crate::TypeFacts for $typ {
+                type IsZst = $crate::Bool<{ $crate::Type::<$typ>::is_zst() }>;
+                type NeedsDrop = $crate::Bool<{ $crate::Type::<$typ>::needs_drop() }>;
+                type HasNiche = $crate::Bool<{ $crate::Type::<$typ>::has_niche() }>;
+                type FitsInPointer = $crate::Bool<{ $crate::__fits_in_pointer::<$typ>() }>;
+            }
+        )+
+    };
+}
+
+impl_type_facts!(
+    (), bool, char,
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+    f32, f64
+);
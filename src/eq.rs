@@ -0,0 +1,321 @@
+//!Type equality witness
+
+use crate::{Type, Tid};
+
+use core::{fmt, marker, mem};
+
+///Proof that `L` and `R` are the same type.
+///
+///It can only be created when compiler knows types are the same, hence generic code can accept it
+///as argument instead of putting bound on its signature, and coerce values between types.
+///
+///## Usage
+///
+///```
+///use type_traits::TypeEq;
+///
+///fn reset<T, O>(value: &mut T, default: O, eq: TypeEq<O, T>) {
+///    *value = eq.coerce(default);
+///}
+///
+///let mut value = 1u32;
+///reset(&mut value, 0u32, TypeEq::new());
+///assert_eq!(value, 0);
+///```
+///
+///```compile_fail
+///use type_traits::TypeEq;
+///
+///let _: TypeEq<u8, u16> = TypeEq::new();
+///```
+pub struct TypeEq<L: ?Sized, R: ?Sized>(marker::PhantomData<Invariant<L, R>>);
+
+//Makes proof invariant over both types, while keeping it `Send` and `Sync`
+type Invariant<L, R> = (fn(&L) -> &L, fn(&R) -> &R);
+
+impl<T: ?Sized> TypeEq<T, T> {
+    ///Proof of equality
+    pub const NEW: Self = TypeEq(marker::PhantomData);
+
+    #[inline(always)]
+    ///Creates proof that type is equal to itself.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+impl<L: ?Sized, R: ?Sized> TypeEq<L, R> {
+    #[inline(always)]
+    ///Reverses proof
+    pub const fn flip(self) -> TypeEq<R, L> {
+        TypeEq(marker::PhantomData)
+    }
+
+    #[inline(always)]
+    ///Coerces reference to `L` into reference to `R`
+    pub const fn coerce_ref(self, value: &L) -> &R {
+        //Safety: L and R are the same type, hence references are of the same layout.
+        unsafe {
+            mem::transmute_copy::<&L, &R>(&value)
+        }
+    }
+
+    #[inline(always)]
+    ///Coerces mutable reference to `L` into mutable reference to `R`
+    pub fn coerce_mut(self, value: &mut L) -> &mut R {
+        //Safety: L and R are the same type, hence references are of the same layout.
+        unsafe {
+            mem::transmute_copy::<&mut L, &mut R>(&value)
+        }
+    }
+}
+
+impl<L, R> TypeEq<L, R> {
+    #[inline(always)]
+    ///Coerces value of `L` into `R`
+    pub const fn coerce(self, value: L) -> R {
+        let value = mem::ManuallyDrop::new(value);
+        //Safety: L and R are the same type.
+        unsafe {
+            mem::transmute_copy::<mem::ManuallyDrop<L>, R>(&value)
+        }
+    }
+
+    #[inline(always)]
+    ///Coerces slice of `L` into slice of `R`
+    pub const fn coerce_slice(self, value: &[L]) -> &[R] {
+        TypeEq::<[L], [R]>(marker::PhantomData).coerce_ref(value)
+    }
+
+    #[inline(always)]
+    ///Coerces mutable slice of `L` into mutable slice of `R`
+    pub fn coerce_slice_mut(self, value: &mut [L]) -> &mut [R] {
+        TypeEq::<[L], [R]>(marker::PhantomData).coerce_mut(value)
+    }
+
+    #[inline(always)]
+    ///Coerces `Option<L>` into `Option<R>`
+    pub const fn coerce_option(self, value: Option<L>) -> Option<R> {
+        TypeEq::<Option<L>, Option<R>>(marker::PhantomData).coerce(value)
+    }
+
+    #[inline(always)]
+    ///Coerces array of `L` into array of `R`
+    pub const fn coerce_array<const N: usize>(self, value: [L; N]) -> [R; N] {
+        TypeEq::<[L; N], [R; N]>(marker::PhantomData).coerce(value)
+    }
+
+}
+
+impl<L: ?Sized, R: ?Sized> Clone for TypeEq<L, R> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: ?Sized, R: ?Sized> Copy for TypeEq<L, R> {}
+
+impl<L: ?Sized, R: ?Sized> fmt::Debug for TypeEq<L, R> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("TypeEq")
+    }
+}
+
+impl<L: ?Sized + 'static, R: ?Sized + 'static> TypeEq<L, R> {
+    #[inline]
+    ///Creates proof, if both types are the same, verifying it at runtime via type id.
+    ///
+    ///```
+    ///use type_traits::TypeEq;
+    ///
+    ///assert!(TypeEq::<u8, u8>::try_new().is_some());
+    ///assert!(TypeEq::<u8, i8>::try_new().is_none());
+    ///```
+    pub fn try_new() -> Option<Self> {
+        if Type::<L>::is::<R>() {
+            Some(TypeEq(marker::PhantomData))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, L: ?Sized + Tid<'a>, R: ?Sized + Tid<'a>> TypeEq<L, R> {
+    #[inline]
+    ///Creates proof, if both types are the same, verifying it at runtime via type id of [Tid::Static](trait.Tid.html#associatedtype.Static).
+    ///
+    ///Unlike [try_new](#method.try_new), types may have lifetime `'a`.
+    ///
+    ///```
+    ///use type_traits::TypeEq;
+    ///
+    ///fn is_text<'a, T: type_traits::Tid<'a>>(_: &T) -> bool {
+    ///    TypeEq::<T, &'a str>::try_new_tid().is_some()
+    ///}
+    ///
+    ///let text = String::from("text");
+    ///assert!(is_text(&text.as_str()));
+    ///assert!(!is_text(&0u8));
+    ///```
+    pub fn try_new_tid() -> Option<Self> {
+        //Tid guarantees `'a` to be the only lifetime, hence same static types imply same types.
+        if Type::<L::Static>::is::<R::Static>() {
+            Some(TypeEq(marker::PhantomData))
+        } else {
+            None
+        }
+    }
+}
+
+#[inline]
+///Casts reference to `T` into reference to `U`, if both are the same type.
+///
+///Requires both types to be `'static` as type identity of non-static type is unsound to rely on.
+///Types with lifetime can be cast via [Tid](trait.Tid.html) based counterpart.
+///
+///## Usage
+///
+///```
+///use type_traits::cast_ref;
+///
+///fn describe<T: ?Sized + 'static>(value: &T) -> &'static str {
+///    match cast_ref::<T, u32>(value) {
+///        Some(0) => "zero",
+///        Some(_) => "number",
+///        None => "unknown",
+///    }
+///}
+///
+///assert_eq!(describe(&0u32), "zero");
+///assert_eq!(describe(&1u32), "number");
+///assert_eq!(describe(&1u64), "unknown");
+///assert_eq!(describe("text"), "unknown");
+///```
+pub fn cast_ref<T: ?Sized + 'static, U: ?Sized + 'static>(value: &T) -> Option<&U> {
+    TypeEq::<T, U>::try_new().map(|eq| eq.coerce_ref(value))
+}
+
+#[inline]
+///Casts reference to `T` into reference to `U`, if both are the same type, allowing lifetime `'a`.
+///
+///## Usage
+///
+///```
+///use type_traits::{Tid, cast_tid_ref};
+///
+///fn describe<'a, T: Tid<'a>>(value: &T) -> &'a str {
+///    match cast_tid_ref::<T, &'a str>(value) {
+///        Some(text) => text,
+///        None => "unknown",
+///    }
+///}
+///
+///let text = String::from("text");
+///assert_eq!(describe(&text.as_str()), "text");
+///assert_eq!(describe(&0u8), "unknown");
+///```
+pub fn cast_tid_ref<'a, T: ?Sized + Tid<'a>, U: ?Sized + Tid<'a>>(value: &T) -> Option<&U> {
+    TypeEq::<T, U>::try_new_tid().map(|eq| eq.coerce_ref(value))
+}
+
+#[inline]
+///Casts mutable reference to `T` into mutable reference to `U`, if both are the same type.
+///
+///Requires both types to be `'static` as type identity of non-static type is unsound to rely on.
+///Types with lifetime can be cast via [Tid](trait.Tid.html) based counterpart.
+///
+///## Usage
+///
+///```
+///use type_traits::cast_mut;
+///
+///fn reset<T: 'static>(value: &mut T) {
+///    if let Some(text) = cast_mut::<T, String>(value) {
+///        text.clear();
+///    }
+///}
+///
+///let mut text = String::from("text");
+///reset(&mut text);
+///assert!(text.is_empty());
+///reset(&mut 1u8);
+///```
+pub fn cast_mut<T: ?Sized + 'static, U: ?Sized + 'static>(value: &mut T) -> Option<&mut U> {
+    TypeEq::<T, U>::try_new().map(move |eq| eq.coerce_mut(value))
+}
+
+#[inline]
+///Casts mutable reference to `T` into mutable reference to `U`, if both are the same type, allowing lifetime `'a`.
+///
+///## Usage
+///
+///```
+///use type_traits::{Tid, cast_tid_mut};
+///
+///fn trim<'a, T: Tid<'a>>(value: &mut T) {
+///    if let Some(text) = cast_tid_mut::<T, &'a str>(value) {
+///        *text = text.trim();
+///    }
+///}
+///
+///let input = String::from(" text ");
+///let mut text = input.as_str();
+///trim(&mut text);
+///assert_eq!(text, "text");
+///```
+pub fn cast_tid_mut<'a, T: ?Sized + Tid<'a>, U: ?Sized + Tid<'a>>(value: &mut T) -> Option<&mut U> {
+    TypeEq::<T, U>::try_new_tid().map(move |eq| eq.coerce_mut(value))
+}
+
+#[inline]
+///Casts `T` into `U`, if both are the same type, otherwise returns original value.
+///
+///Requires both types to be `'static` as type identity of non-static type is unsound to rely on.
+///Types with lifetime can be cast via [Tid](trait.Tid.html) based counterpart.
+///
+///## Usage
+///
+///```
+///use type_traits::cast;
+///
+///fn to_string<T: ToString + 'static>(value: T) -> String {
+///    match cast::<T, String>(value) {
+///        Ok(value) => value,
+///        Err(value) => value.to_string(),
+///    }
+///}
+///
+///assert_eq!(to_string(String::from("text")), "text");
+///assert_eq!(to_string(1), "1");
+///```
+pub fn cast<T: 'static, U: 'static>(value: T) -> Result<U, T> {
+    match TypeEq::<T, U>::try_new() {
+        Some(eq) => Ok(eq.coerce(value)),
+        None => Err(value),
+    }
+}
+
+#[inline]
+///Casts `T` into `U`, if both are the same type, otherwise returns original value, allowing lifetime `'a`.
+///
+///## Usage
+///
+///```
+///use type_traits::{Tid, cast_tid};
+///
+///fn len<'a, T: Tid<'a>>(value: T) -> Option<usize> {
+///    cast_tid::<T, &'a [u8]>(value).ok().map(|bytes| bytes.len())
+///}
+///
+///let bytes = vec![1u8, 2, 3];
+///assert_eq!(len(bytes.as_slice()), Some(3));
+///assert_eq!(len(1u8), None);
+///```
+pub fn cast_tid<'a, T: Tid<'a>, U: Tid<'a>>(value: T) -> Result<U, T> {
+    match TypeEq::<T, U>::try_new_tid() {
+        Some(eq) => Ok(eq.coerce(value)),
+        None => Err(value),
+    }
+}
@@ -0,0 +1,199 @@
+//!Stable type fingerprint
+
+use crate::Type;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut idx = 0;
+    while idx < bytes.len() {
+        hash ^= bytes[idx] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        idx += 1;
+    }
+    hash
+}
+
+///Computes stable hash of type's name together with hashes of its components.
+///
+///Components are usually [StableName::NAME_HASH](trait.StableName.html#associatedconstant.NAME_HASH)
+///of generic parameters or array length, which allows to combine names of generic types.
+///Without components it is `NAME_HASH` of non-generic type.
+pub const fn stable_name_hash(name: &str, components: &[u64]) -> u64 {
+    let mut hash = fnv1a(FNV_OFFSET, name.as_bytes());
+    let mut idx = 0;
+    while idx < components.len() {
+        hash = fnv1a(hash, &components[idx].to_le_bytes());
+        idx += 1;
+    }
+    hash
+}
+
+///Type's name that stays the same across builds, compiler versions and processes.
+///
+///Unlike [type_name](https://doc.rust-lang.org/core/any/fn.type_name.html), value is chosen by
+///user, hence it is up to user to make it unique within context of its usage.
+///
+///Generic types cannot build `NAME` out of their parameters in const context, hence their `NAME`
+///describes only shape of the type, while parameters are combined into `NAME_HASH` via
+///[stable_name_hash](fn.stable_name_hash.html).
+///Implementations are provided for primitives, arrays, slices, tuples of up to 12 elements, `Option`, references and pointers.
+///
+///## Uniqueness
+///
+///`NAME_HASH` is required, so that every implementation decides what identifies the type.
+///Generic type must combine `NAME_HASH` of all its parameters, otherwise its instances with
+///the same layout (e.g. `Segment<u32>` and `Segment<f32>`) would have the same fingerprint,
+///making it impossible to reject mismatch between reader and writer.
+///
+///```compile_fail
+///use type_traits::StableName;
+///
+///struct Segment<T>([T; 16]);
+///
+///impl<T: StableName> StableName for Segment<T> {
+///    const NAME: &'static str = "my_proto::Segment";
+///}
+///```
+///
+///## Usage
+///
+///```
+///use type_traits::{Type, StableName, stable_name_hash};
+///
+///#[repr(C)]
+///struct Header {
+///    len: u32,
+///    flags: u32,
+///}
+///
+///impl StableName for Header {
+///    const NAME: &'static str = "my_proto::Header";
+///    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[]);
+///}
+///
+///#[repr(C)]
+///struct Segment<T> {
+///    header: Header,
+///    data: [T; 16],
+///}
+///
+///impl<T: StableName> StableName for Segment<T> {
+///    const NAME: &'static str = "my_proto::Segment";
+///    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[T::NAME_HASH]);
+///}
+///
+///const HEADER: u64 = Type::<Header>::fingerprint();
+///assert_ne!(HEADER, Type::<u64>::fingerprint());
+///assert_ne!(Type::<Segment<u32>>::fingerprint(), Type::<Segment<i32>>::fingerprint());
+///```
+pub trait StableName {
+    ///Type's name
+    const NAME: &'static str;
+    ///Stable hash of type's name, including `NAME_HASH` of its generic parameters.
+    ///
+    ///Non-generic type should use `stable_name_hash(Self::NAME, &[])`.
+    const NAME_HASH: u64;
+}
+
+macro_rules! impl_stable_name {
+    ($($typ:ty),+) => {
+        $(
+            impl StableName for $typ {
+                const NAME: &'static str = stringify!($typ);
+                const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[]);
+            }
+        )+
+    };
+}
+
+impl_stable_name!(
+    (), bool, char, str,
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+    f32, f64
+);
+
+impl<T: StableName, const N: usize> StableName for [T; N] {
+    const NAME: &'static str = "[T; N]";
+    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[T::NAME_HASH, N as u64]);
+}
+
+impl<T: StableName> StableName for [T] {
+    const NAME: &'static str = "[T]";
+    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[T::NAME_HASH]);
+}
+
+impl<T: StableName> StableName for Option<T> {
+    const NAME: &'static str = "Option<T>";
+    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[T::NAME_HASH]);
+}
+
+macro_rules! impl_stable_name_ptr {
+    ($($name:literal $typ:ty),+) => {
+        $(
+            impl<T: ?Sized + StableName> StableName for $typ {
+                const NAME: &'static str = $name;
+                const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[T::NAME_HASH]);
+            }
+        )+
+    };
+}
+
+impl_stable_name_ptr!(
+    "*const T" *const T,
+    "*mut T" *mut T,
+    "&T" &T,
+    "&mut T" &mut T
+);
+
+macro_rules! impl_stable_name_tuple {
+    ($($name:literal ($($param:ident),+);)+) => {
+        $(
+            impl<$($param: StableName),+> StableName for ($($param,)+) {
+                const NAME: &'static str = $name;
+                const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[$($param::NAME_HASH),+]);
+            }
+        )+
+    };
+}
+
+impl_stable_name_tuple!(
+    "(T1,)" (T1);
+    "(T1, T2)" (T1, T2);
+    "(T1, T2, T3)" (T1, T2, T3);
+    "(T1, T2, T3, T4)" (T1, T2, T3, T4);
+    "(T1, T2, T3, T4, T5)" (T1, T2, T3, T4, T5);
+    "(T1, T2, T3, T4, T5, T6)" (T1, T2, T3, T4, T5, T6);
+    "(T1, T2, T3, T4, T5, T6, T7)" (T1, T2, T3, T4, T5, T6, T7);
+    "(T1, T2, T3, T4, T5, T6, T7, T8)" (T1, T2, T3, T4, T5, T6, T7, T8);
+    "(T1, T2, T3, T4, T5, T6, T7, T8, T9)" (T1, T2, T3, T4, T5, T6, T7, T8, T9);
+    "(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)" (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+    "(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)" (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+    "(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)" (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+);
+
+impl<T: StableName> Type<T> {
+    ///Returns type's fingerprint, that is stable across builds, compiler versions and processes.
+    ///
+    ///Fingerprint is `FNV-1a` hash of [StableName::NAME_HASH](trait.StableName.html#associatedconstant.NAME_HASH), size, alignment and whether type needs drop.
+    ///Note that size and alignment are platform dependent, hence fingerprint of the same type may differ between targets.
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///const FINGERPRINT: u64 = Type::<u32>::fingerprint();
+    ///
+    ///assert_eq!(FINGERPRINT, Type::<u32>::fingerprint());
+    ///assert_ne!(FINGERPRINT, Type::<i32>::fingerprint());
+    ///assert_ne!(FINGERPRINT, Type::<f32>::fingerprint());
+    ///```
+    pub const fn fingerprint() -> u64 {
+        let mut hash = T::NAME_HASH;
+        hash = fnv1a(hash, &(Self::size() as u64).to_le_bytes());
+        hash = fnv1a(hash, &(Self::align() as u64).to_le_bytes());
+        fnv1a(hash, &[Self::needs_drop() as u8])
+    }
+}
@@ -0,0 +1,200 @@
+//!Trait implementation detection
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impls_trait {
+    ($typ:ty: $($trait:tt)+) => {{
+        trait DoesNotImpl {
+            const IMPLS: bool = false;
+        }
+
+        struct Wrapper<T: ?Sized>(core::marker::PhantomData<T>);
+
+        impl<T: ?Sized> DoesNotImpl for Wrapper<T> {}
+
+        #[allow(dead_code)]
+        impl<T: ?Sized + $($trait)+> Wrapper<T> {
+            const IMPLS: bool = true;
+        }
+
+        <Wrapper<$typ>>::IMPLS
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impls_expr {
+    //Final trait
+    ([$typ:ty] [$($out:tt)*] [$($trait:tt)+]) => {
+        $($out)* $crate::__impls_trait!($typ: $($trait)+)
+    };
+    //Binary operators
+    ([$typ:ty] [$($out:tt)*] [$($trait:tt)+] + $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* $crate::__impls_trait!($typ: $($trait)+) &&] [] $($rest)+)
+    };
+    ([$typ:ty] [$($out:tt)*] [$($trait:tt)+] & $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* $crate::__impls_trait!($typ: $($trait)+) &&] [] $($rest)+)
+    };
+    ([$typ:ty] [$($out:tt)*] [$($trait:tt)+] | $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* $crate::__impls_trait!($typ: $($trait)+) ||] [] $($rest)+)
+    };
+    ([$typ:ty] [$($out:tt)*] [$($trait:tt)+] ^ $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* $crate::__impls_trait!($typ: $($trait)+) ^] [] $($rest)+)
+    };
+    //Unary operator
+    ([$typ:ty] [$($out:tt)*] [] ! $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* !] [] $($rest)+)
+    };
+    //Group
+    ([$typ:ty] [$($out:tt)*] [] ($($group:tt)+)) => {
+        $($out)* ($crate::__impls_expr!([$typ] [] [] $($group)+))
+    };
+    ([$typ:ty] [$($out:tt)*] [] ($($group:tt)+) + $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* ($crate::__impls_expr!([$typ] [] [] $($group)+)) &&] [] $($rest)+)
+    };
+    ([$typ:ty] [$($out:tt)*] [] ($($group:tt)+) & $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* ($crate::__impls_expr!([$typ] [] [] $($group)+)) &&] [] $($rest)+)
+    };
+    ([$typ:ty] [$($out:tt)*] [] ($($group:tt)+) | $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* ($crate::__impls_expr!([$typ] [] [] $($group)+)) ||] [] $($rest)+)
+    };
+    ([$typ:ty] [$($out:tt)*] [] ($($group:tt)+) ^ $($rest:tt)+) => {
+        $crate::__impls_expr!([$typ] [$($out)* ($crate::__impls_expr!([$typ] [] [] $($group)+)) ^] [] $($rest)+)
+    };
+    //Part of trait path
+    ([$typ:ty] [$($out:tt)*] [$($trait:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__impls_expr!([$typ] [$($out)*] [$($trait)* $token] $($rest)*)
+    };
+}
+
+///Evaluates to `const bool`, indicating whether concrete type implements traits.
+///
+///Traits can be combined using following operators:
+///
+///- `!` - type does not implement trait;
+///- `+` or `&` - both conditions hold;
+///- `|` - any condition holds;
+///- `^` - exactly one condition holds;
+///- `()` - grouping.
+///
+///Operator `^` binds tighter than `+` and `&`, which bind tighter than `|`.
+///
+///## Limitations
+///
+///- Type must be concrete, as generic parameters cannot be referred from within macro expansion.
+///- Trait's generic arguments must not contain operators above (e.g. `PartialEq<&str>`), use type alias instead.
+///
+///## Usage
+///
+///```
+///use type_traits::impls;
+///
+///use core::cell::Cell;
+///use std::rc::Rc;
+///
+///const IS_SEND_NOT_SYNC: bool = impls!(Cell<u8>: Send & !Sync);
+///assert!(IS_SEND_NOT_SYNC);
+///
+///assert!(impls!(u8: Copy + Clone + core::fmt::Debug));
+///assert!(impls!(Rc<u8>: !Send & !Sync));
+///assert!(impls!(String: Copy | Clone));
+///assert!(impls!(String: Copy ^ Clone));
+///assert!(!impls!(String: (Copy | Clone) & !Clone));
+///assert!(impls!(fn(u8) -> u8: Fn(u8) -> u8));
+///assert!(impls!(u8: Into<u32> + From<bool>));
+///assert!(impls!(str: !Sized));
+///```
+#[macro_export]
+macro_rules! impls {
+    ($typ:ty: $($expr:tt)+) => {
+        $crate::__impls_expr!([$typ] [] [] $($expr)+)
+    };
+}
+
+///Asserts at compile time that concrete type implements traits.
+///
+///Compilation error names trait, which is not implemented.
+///
+///## Usage
+///
+///```
+///use type_traits::assert_impl;
+///
+///assert_impl!(u8: Send + Sync + Copy);
+///assert_impl!(str: Send + Sync + core::fmt::Debug);
+///assert_impl!(fn(u8) -> u8: Fn(u8) -> u8 + Send);
+///```
+///
+///```compile_fail
+///use type_traits::assert_impl;
+///
+///assert_impl!(std::rc::Rc<u8>: Send);
+///```
+#[macro_export]
+macro_rules! assert_impl {
+    ($typ:ty: $($trait:tt)+) => {
+        const _: () = {
+            const fn assert_impl<T: ?Sized + $($trait)+>() {}
+            assert_impl::<$typ>();
+        };
+    };
+}
+
+///Asserts at compile time that concrete type does not implement traits.
+///
+///Traits are specified using the same syntax as in [impls](macro.impls.html), and assertion fails
+///if the whole expression holds. Hence `assert_not_impl!(T: Send + Sync)` forbids implementing both
+///traits at once, while `assert_not_impl!(T: Send | Sync)` forbids implementing any of them.
+///
+///## Usage
+///
+///```
+///use type_traits::assert_not_impl;
+///
+///assert_not_impl!(core::cell::Cell<u8>: Sync);
+///assert_not_impl!(std::rc::Rc<u8>: Send | Sync);
+///assert_not_impl!(String: Copy);
+///```
+///
+///```compile_fail
+///use type_traits::assert_not_impl;
+///
+///assert_not_impl!(u8: Send | Copy);
+///```
+#[macro_export]
+macro_rules! assert_not_impl {
+    ($typ:ty: $($expr:tt)+) => {
+        const _: () = assert!(
+            !$crate::impls!($typ: $($expr)+),
+            concat!("`", stringify!($typ), "` must not implement `", stringify!($($expr)+), "`")
+        );
+    };
+}
+
+///Asserts at compile time that traits are dyn compatible (object safe).
+///
+///## Usage
+///
+///```
+///use type_traits::assert_obj_safe;
+///
+///trait Plugin {
+///    fn name(&self) -> &str;
+///}
+///
+///assert_obj_safe!(Plugin, core::fmt::Debug, Fn(u8) -> u8);
+///```
+///
+///```compile_fail
+///use type_traits::assert_obj_safe;
+///
+///assert_obj_safe!(Clone);
+///```
+#[macro_export]
+macro_rules! assert_obj_safe {
+    ($($trait:path),+ $(,)?) => {
+        $(
+            const _: Option<&dyn $trait> = None;
+        )+
+    };
+}
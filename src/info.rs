@@ -0,0 +1,254 @@
+//!Runtime type information
+
+use crate::{Type, ShortName, LayoutMismatch, Relation};
+use crate::check;
+
+use core::{any, fmt, hash, ptr};
+
+unsafe fn drop_erased<T>(ptr: *mut u8) {
+    ptr::drop_in_place(ptr as *mut T)
+}
+
+///Type information as value
+///
+///Unlike [Type](struct.Type.html), it is not generic, hence can be passed around by type-erased code.
+///
+///Equality and hashing are defined by type id only.
+///
+///## Usage
+///
+///```
+///use type_traits::{Type, TypeInfo};
+///
+///const INFO: [TypeInfo; 2] = [TypeInfo::of::<u32>(), TypeInfo::of::<String>()];
+///
+///assert_eq!(INFO[0].id(), Type::<u32>::type_id());
+///assert_eq!(INFO[0].name(), "u32");
+///assert_eq!(INFO[0].size(), 4);
+///assert_eq!(INFO[0].align(), 4);
+///assert!(!INFO[0].needs_drop());
+///assert!(INFO[1].needs_drop());
+///assert_ne!(INFO[0], INFO[1]);
+///
+///let mut value = core::mem::ManuallyDrop::new(String::from("erased"));
+///unsafe {
+///    INFO[1].drop_in_place(&mut *value as *mut String as *mut u8);
+///}
+///```
+#[derive(Copy, Clone)]
+pub struct TypeInfo {
+    id: any::TypeId,
+    name: fn() -> &'static str,
+    size: usize,
+    align: usize,
+    needs_drop: bool,
+    drop_in_place: unsafe fn(*mut u8),
+}
+
+impl TypeInfo {
+    #[inline(always)]
+    ///Creates information of type `T`
+    pub const fn of<T: 'static>() -> Self {
+        Self {
+            id: Type::<T>::type_id(),
+            name: any::type_name::<T>,
+            size: Type::<T>::size(),
+            align: Type::<T>::align(),
+            needs_drop: Type::<T>::needs_drop(),
+            drop_in_place: drop_erased::<T>,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns type id, same as [Type::type_id](struct.Type.html#method.type_id)
+    pub const fn id(&self) -> any::TypeId {
+        self.id
+    }
+
+    #[inline(always)]
+    ///Returns type name, same as [Type::name](struct.Type.html#method.name)
+    pub fn name(&self) -> &'static str {
+        (self.name)()
+    }
+
+    #[inline(always)]
+    ///Returns type name without module paths, same as [Type::short_name](struct.Type.html#method.short_name)
+    pub fn short_name(&self) -> ShortName<'static> {
+        ShortName::new(self.name())
+    }
+
+    #[inline(always)]
+    ///Returns object size
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline(always)]
+    ///Returns minimum alignment
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+
+    #[inline(always)]
+    ///Returns whether type has `Drop` implementation with side effects
+    pub const fn needs_drop(&self) -> bool {
+        self.needs_drop
+    }
+
+    #[inline(always)]
+    ///Returns whether type is ZST
+    pub const fn is_zst(&self) -> bool {
+        self.size == 0
+    }
+
+    #[inline(always)]
+    ///Returns type-erased `drop_in_place` of the type
+    pub const fn drop_fn(&self) -> unsafe fn(*mut u8) {
+        self.drop_in_place
+    }
+
+    #[inline(always)]
+    ///Drops value pointed by `ptr` in place
+    ///
+    ///## Safety
+    ///
+    ///Same requirements as for [drop_in_place](https://doc.rust-lang.org/core/ptr/fn.drop_in_place.html),
+    ///and `ptr` must point to the value of the described type.
+    pub unsafe fn drop_in_place(&self, ptr: *mut u8) {
+        (self.drop_in_place)(ptr)
+    }
+
+    #[inline]
+    ///Checks described type requires no call to `Drop::drop`, same as [Check::no_need_drop](struct.Check.html#method.no_need_drop)
+    pub const fn check_no_need_drop(&self) -> Result<(), LayoutMismatch> {
+        check::no_need_drop(self.needs_drop)
+    }
+
+    #[inline]
+    ///Checks described type is not ZST, same as [Check::is_not_zst](struct.Check.html#method.is_not_zst)
+    pub const fn check_not_zst(&self) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::NotEqual, 0)
+    }
+
+    #[inline]
+    ///Checks described type is ZST, same as [Check::is_zst](struct.Check.html#method.is_zst)
+    pub const fn check_zst(&self) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::Equal, 0)
+    }
+
+    #[inline]
+    ///Checks described type size is exactly `expected`, same as [Check::size_is](struct.Check.html#method.size_is)
+    pub const fn check_size_is(&self, expected: usize) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::Equal, expected)
+    }
+
+    #[inline]
+    ///Checks described type size is less or equal to `expected`, same as [Check::size_at_most](struct.Check.html#method.size_at_most)
+    pub const fn check_size_at_most(&self, expected: usize) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::AtMost, expected)
+    }
+
+    #[inline]
+    ///Checks described type size is greater or equal to `expected`, same as [Check::size_at_least](struct.Check.html#method.size_at_least)
+    pub const fn check_size_at_least(&self, expected: usize) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::AtLeast, expected)
+    }
+
+    #[inline]
+    ///Checks described type minimum alignment is exactly `expected`, same as [Check::align_is](struct.Check.html#method.align_is)
+    pub const fn check_align_is(&self, expected: usize) -> Result<(), LayoutMismatch> {
+        check::align(self.align, Relation::Equal, expected)
+    }
+
+    #[inline]
+    ///Checks described type minimum alignment is greater or equal to `expected`, same as [Check::align_at_least](struct.Check.html#method.align_at_least)
+    pub const fn check_align_at_least(&self, expected: usize) -> Result<(), LayoutMismatch> {
+        check::align(self.align, Relation::AtLeast, expected)
+    }
+
+    #[inline]
+    ///Checks described type minimum alignment is less or equal to `expected`, same as [Check::align_at_most](struct.Check.html#method.align_at_most)
+    pub const fn check_align_at_most(&self, expected: usize) -> Result<(), LayoutMismatch> {
+        check::align(self.align, Relation::AtMost, expected)
+    }
+
+    #[inline]
+    ///Checks described type size is the same as of `expected`, same as [Check2::is_same_size](struct.Check2.html#method.is_same_size)
+    pub const fn check_same_size(&self, expected: &TypeInfo) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::Equal, expected.size)
+    }
+
+    #[inline]
+    ///Checks described type minimum alignment is the same as of `expected`, same as [Check2::is_same_align](struct.Check2.html#method.is_same_align)
+    pub const fn check_same_align(&self, expected: &TypeInfo) -> Result<(), LayoutMismatch> {
+        check::align(self.align, Relation::Equal, expected.align)
+    }
+
+    #[inline]
+    ///Checks described type size is multiple of non-zero size of `expected`, same as [Check2::is_left_size_multiple](struct.Check2.html#method.is_left_size_multiple)
+    pub const fn check_size_multiple_of(&self, expected: &TypeInfo) -> Result<(), LayoutMismatch> {
+        check::size(self.size, Relation::MultipleOf, expected.size)
+    }
+
+    #[inline]
+    ///Checks described type is the same as `expected`
+    pub fn check_type(&self, expected: &TypeInfo) -> Result<(), LayoutMismatch> {
+        match self.id == expected.id {
+            true => Ok(()),
+            false => Err(LayoutMismatch::Type {
+                actual: self.name(),
+                expected: expected.name(),
+            }),
+        }
+    }
+
+    #[inline]
+    ///Checks described type has the same size and minimum alignment as `expected`
+    ///
+    ///Intended to validate type information, received at runtime (e.g. by plugin loader), when type id
+    ///cannot be relied upon.
+    ///Niche and padding are not part of type information, hence they can be checked only via [Check](struct.Check.html).
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::{TypeInfo, LayoutMismatch, Relation};
+    ///
+    ///let received = TypeInfo::of::<u64>();
+    ///assert_eq!(received.check_layout(&TypeInfo::of::<i64>()), Ok(()));
+    ///assert_eq!(received.check_layout(&TypeInfo::of::<u32>()), Err(LayoutMismatch::Size { actual: 8, relation: Relation::Equal, expected: 4 }));
+    ///```
+    pub const fn check_layout(&self, expected: &TypeInfo) -> Result<(), LayoutMismatch> {
+        match check::size(self.size, Relation::Equal, expected.size) {
+            Ok(()) => check::align(self.align, Relation::Equal, expected.align),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl PartialEq for TypeInfo {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TypeInfo {}
+
+impl hash::Hash for TypeInfo {
+    #[inline(always)]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl fmt::Debug for TypeInfo {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("TypeInfo")
+           .field("name", &self.name())
+           .field("size", &self.size)
+           .field("align", &self.align)
+           .field("needs_drop", &self.needs_drop)
+           .finish()
+    }
+}
@@ -0,0 +1,360 @@
+//!Const layout utilities
+
+use crate::{Type, TypeInfo};
+
+use core::{alloc, marker, mem};
+
+///Const builder of `repr(C)` layout
+///
+///Layout is computed by the same algorithm as for `#[repr(C)]` struct, where `N` is number of fields.
+///
+///All methods are `const`, hence it is possible to verify layout at compile time.
+///
+///## Usage
+///
+///```
+///use type_traits::ReprC;
+///use core::mem;
+///
+///#[repr(C)]
+///struct Ffi {
+///    a: u8,
+///    b: u32,
+///    c: u16,
+///}
+///
+///const LAYOUT: ReprC<3> = ReprC::new().field::<u8>().field::<u32>().field::<u16>().finish();
+///
+///const _: () = assert!(LAYOUT.offset(0) == mem::offset_of!(Ffi, a));
+///const _: () = assert!(LAYOUT.offset(1) == mem::offset_of!(Ffi, b));
+///const _: () = assert!(LAYOUT.offset(2) == mem::offset_of!(Ffi, c));
+///const _: () = assert!(LAYOUT.size() == mem::size_of::<Ffi>());
+///const _: () = assert!(LAYOUT.align() == mem::align_of::<Ffi>());
+///
+///assert_eq!(LAYOUT.offsets(), &[0, 4, 8]);
+///assert_eq!(LAYOUT.layout(), core::alloc::Layout::new::<Ffi>());
+///```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReprC<const N: usize> {
+    end: usize,
+    align: usize,
+    len: usize,
+    offsets: [usize; N],
+}
+
+impl<const N: usize> ReprC<N> {
+    #[inline(always)]
+    ///Creates empty layout
+    pub const fn new() -> Self {
+        Self {
+            end: 0,
+            align: 1,
+            len: 0,
+            offsets: [0; N],
+        }
+    }
+
+    #[inline(always)]
+    ///Extends layout with field of type `T`
+    ///
+    ///Panics if all `N` fields are already added.
+    pub const fn field<T>(self) -> Self {
+        self.field_layout(Type::<T>::layout())
+    }
+
+    ///Extends layout with field of specified layout
+    ///
+    ///Panics if all `N` fields are already added or size overflows.
+    pub const fn field_layout(mut self, layout: alloc::Layout) -> Self {
+        assert!(self.len < N, "ReprC has more fields than declared");
+
+        let offset = match round_up(self.end, layout.align()) {
+            Some(offset) => offset,
+            None => panic!("ReprC size overflow"),
+        };
+        self.end = match offset.checked_add(layout.size()) {
+            Some(end) => end,
+            None => panic!("ReprC size overflow"),
+        };
+        if layout.align() > self.align {
+            self.align = layout.align();
+        }
+        self.offsets[self.len] = offset;
+        self.len += 1;
+        self
+    }
+
+    #[inline(always)]
+    ///Finishes building, verifying that all `N` fields are added.
+    pub const fn finish(self) -> Self {
+        assert!(self.len == N, "ReprC has less fields than declared");
+        self
+    }
+
+    #[inline(always)]
+    ///Returns number of added fields
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether no field is added yet
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    ///Returns total size, including trailing padding
+    pub const fn size(&self) -> usize {
+        match round_up(self.end, self.align) {
+            Some(size) => size,
+            None => panic!("ReprC size overflow"),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns minimum alignment
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+
+    #[inline(always)]
+    ///Returns offset of field with index `idx`
+    ///
+    ///Panics if field is not added yet.
+    pub const fn offset(&self, idx: usize) -> usize {
+        assert!(idx < self.len, "ReprC field is not added");
+        self.offsets[idx]
+    }
+
+    #[inline(always)]
+    ///Returns offsets of all fields.
+    ///
+    ///Offsets of fields, that are not added yet, are zero.
+    pub const fn offsets(&self) -> &[usize; N] {
+        &self.offsets
+    }
+
+    #[inline(always)]
+    ///Returns memory layout
+    pub const fn layout(&self) -> alloc::Layout {
+        match alloc::Layout::from_size_align(self.size(), self.align) {
+            Ok(layout) => layout,
+            Err(_) => panic!("ReprC size overflow"),
+        }
+    }
+}
+
+impl<const N: usize> Default for ReprC<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline(always)]
+const fn round_up(value: usize, align: usize) -> Option<usize> {
+    match value.checked_add(align - 1) {
+        Some(value) => Some(value & !(align - 1)),
+        None => None,
+    }
+}
+
+///Information about struct's field
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    name: &'static str,
+    info: TypeInfo,
+    offset: usize,
+    padding: usize,
+    inner_padding: usize,
+}
+
+impl Field {
+    #[inline(always)]
+    ///Returns field name.
+    ///
+    ///For tuple structs it is index of the field.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline(always)]
+    ///Returns field type information
+    pub const fn info(&self) -> &TypeInfo {
+        &self.info
+    }
+
+    #[inline(always)]
+    ///Returns field offset within struct
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline(always)]
+    ///Returns field size
+    pub const fn size(&self) -> usize {
+        self.info.size()
+    }
+
+    #[inline(always)]
+    ///Returns number of padding bytes between end of the field and next field in memory or end of the struct.
+    pub const fn padding(&self) -> usize {
+        self.padding
+    }
+
+    #[inline(always)]
+    ///Returns number of padding bytes within field's type itself, as described by its [TypeLayout](trait.TypeLayout.html)
+    pub const fn inner_padding(&self) -> usize {
+        self.inner_padding
+    }
+}
+
+///Describes struct's fields layout
+///
+///Primitive types have no fields, hence their description is empty.
+///
+///Can be derived with `derive` feature via `#[derive(TypeLayout)]`, which requires all field types to be `'static`
+///and implement `TypeLayout`, so that padding within nested fields is accounted.
+///
+///## Safety
+///
+///`FIELDS` must describe every field of `Self` with its actual type, offset and padding within field's type,
+///and `PADDING_BYTES` must be total number of padding bytes within `Self`, as it is relied upon by
+///[Assert::HAS_NO_PADDING](struct.Assert.html#associatedconstant.HAS_NO_PADDING) (e.g. to read type's bytes).
+///Empty `FIELDS` is allowed only for types without fields, such as primitives and arrays.
+///
+///Hence manual implementation must be `unsafe`:
+///
+///```compile_fail
+///use type_traits::{Field, TypeLayout};
+///
+///#[repr(C)]
+///struct Manual {
+///    a: u8,
+///    b: u32,
+///}
+///
+///impl TypeLayout for Manual {
+///    const FIELDS: &'static [Field] = &[];
+///}
+///```
+///
+///## Usage
+///
+///```
+///use type_traits::{Type, TypeLayout};
+///
+///#[cfg(feature = "derive")]
+///#[derive(TypeLayout)]
+///struct Foo {
+///    a: u8,
+///    b: u32,
+///}
+///
+///#[cfg(feature = "derive")]
+///{
+///    let fields = Type::<Foo>::fields();
+///    assert_eq!(fields[0].name(), "a");
+///    assert_eq!(fields[1].name(), "b");
+///    assert_eq!(fields[0].padding() + fields[1].padding(), 3);
+///}
+///```
+pub unsafe trait TypeLayout: Sized {
+    ///Fields in order of declaration.
+    const FIELDS: &'static [Field];
+    ///Total number of padding bytes, including padding within fields.
+    ///
+    ///By default it is computed from `FIELDS`.
+    const PADDING_BYTES: usize = padding_bytes(Self::FIELDS, mem::size_of::<Self>());
+}
+
+const fn padding_bytes(fields: &[Field], size: usize) -> usize {
+    if fields.is_empty() {
+        return 0;
+    }
+
+    let mut size = size;
+    let mut idx = 0;
+    while idx < fields.len() {
+        size = size - fields[idx].size() + fields[idx].inner_padding();
+        idx += 1;
+    }
+    size
+}
+
+macro_rules! impl_primitive_layout {
+    ($($typ:ty),+) => {
+        $(
+            unsafe impl TypeLayout for $typ {
+                const FIELDS: &'static [Field] = &[];
+            }
+        )+
+    };
+}
+
+impl_primitive_layout!(
+    (), bool, char,
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+    f32, f64
+);
+
+macro_rules! impl_pointer_layout {
+    ($($typ:ty),+) => {
+        $(
+            unsafe impl<T: ?Sized> TypeLayout for $typ {
+                const FIELDS: &'static [Field] = &[];
+            }
+        )+
+    };
+}
+
+impl_pointer_layout!(
+    &T, &mut T, *const T, *mut T, core::ptr::NonNull<T>, marker::PhantomData<T>
+);
+
+unsafe impl<T: TypeLayout, const N: usize> TypeLayout for [T; N] {
+    const FIELDS: &'static [Field] = &[];
+    //Array elements are laid out without gaps, as size is multiple of alignment.
+    const PADDING_BYTES: usize = T::PADDING_BYTES * N;
+}
+
+#[doc(hidden)]
+///Creates fields table, used by derive macro.
+pub const fn fields<const N: usize>(fields: [(&'static str, TypeInfo, usize, usize); N], size: usize) -> [Field; N] {
+    let mut result = [Field {
+        name: "",
+        info: TypeInfo::of::<()>(),
+        offset: 0,
+        padding: 0,
+        inner_padding: 0,
+    }; N];
+
+    let mut idx = 0;
+    while idx < N {
+        let (name, info, offset, inner_padding) = fields[idx];
+        let end = offset + info.size();
+
+        //Find closest field after the end of this field, ignoring ZSTs as they occupy no memory.
+        let mut next = size;
+        let mut other = 0;
+        while other < N {
+            let other_offset = fields[other].2;
+            if other != idx && fields[other].1.size() != 0 && other_offset >= end && other_offset < next {
+                next = other_offset;
+            }
+            other += 1;
+        }
+
+        result[idx] = Field {
+            name,
+            info,
+            offset,
+            padding: if info.size() == 0 { 0 } else { next - end },
+            inner_padding,
+        };
+        idx += 1;
+    }
+
+    result
+}
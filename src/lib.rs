@@ -2,20 +2,94 @@
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::style)]
 
-use core::{mem, marker};
+use core::{alloc, any, mem, marker};
+
+mod msg;
+use msg::Message;
+mod fingerprint;
+pub use fingerprint::{StableName, stable_name_hash};
+mod name;
+pub use name::ShortName;
+mod info;
+pub use info::TypeInfo;
+mod val;
+pub use val::{Metadata, ValueInfo};
+mod layout;
+pub use layout::{ReprC, Field, TypeLayout};
+#[doc(hidden)]
+pub use layout::fields as __layout_fields;
+#[cfg(feature = "derive")]
+pub use type_traits_derive::{TypeLayout, layout};
+mod cast;
+pub use cast::{transmute_checked, transmute_ref_checked, transmute_mut_checked, ptr_cast_checked, ptr_cast_mut_checked};
+pub use cast::{SliceCastError, cast_slice, cast_slice_mut, try_cast_slice, try_cast_slice_mut};
+mod assert;
+pub use assert::{SizeIs, SizeAtMost, SizeAtLeast, AlignIs, AlignAtLeast, AlignAtMost};
+mod impls;
+mod eq;
+pub use eq::{TypeEq, cast, cast_ref, cast_mut, cast_tid, cast_tid_ref, cast_tid_mut};
+mod tid;
+pub use tid::{Tid, AnyRef, AnyMut};
+#[cfg(feature = "alloc")]
+pub use tid::AnyBox;
+#[cfg(feature = "derive")]
+pub use type_traits_derive::Tid;
+mod token;
+mod cond;
+pub use cond::{Bool, True, False, Boolean, If, TypeFacts};
+#[doc(hidden)]
+pub use cond::fits_in_pointer as __fits_in_pointer;
+mod list;
+pub use list::{TypeList, AssertAll, AllFitIn};
+mod check;
+pub use check::{Check, Check2, LayoutMismatch, Relation};
+
+//Makes tokens covariant over `T`, while being always `Send` and `Sync`
+type Covariant<T> = marker::PhantomData<fn() -> *const T>;
 
 ///Type information
+///
+///Zero sized token, that is always `Send + Sync` and covariant over `T`.
+///
+///Tokens of `'static` types can be compared and hashed, with identity of `T` being the value.
+///
+///`Debug` output contains only name of `T`: token is implemented for unsized types, whose size
+///and alignment are not known without a value (e.g. `str` or `dyn Trait`), and stable Rust
+///cannot specialize output for sized ones. Use [size](#method.size) and [align](#method.align),
+///[size_of_val](#method.size_of_val) when value is at hand, or [Assert](struct.Assert.html), which
+///includes both in its `Debug` output.
+///
+///```
+///use type_traits::Type;
+///
+///const U8: Type<u8> = Type::new();
+///
+///assert_eq!(U8, Type::<u8>::new());
+///assert_ne!(U8, Type::<i8>::new());
+///assert_eq!(format!("{:?}", U8), "Type<u8>");
+///assert_eq!(format!("{:?}", Type::<str>::new()), "Type<str>");
+///```
 #[repr(transparent)]
-pub struct Type<T>(marker::PhantomData<T>);
+pub struct Type<T: ?Sized>(Covariant<T>);
+
+impl<T: ?Sized> Type<T> {
+    #[inline(always)]
+    ///Creates new token
+    pub const fn new() -> Self {
+        Self(marker::PhantomData)
+    }
 
-impl<T> Type<T> {
     #[inline(always)]
     ///Get type id (different from [TypeId](https://doc.rust-lang.org/core/any/struct.TypeId.html))
     ///
+    ///This id is address of type's name, hence it is only as unique as the name itself.
+    ///Prefer [type_id](#method.type_id) whenever type is `'static`.
+    ///
     ///Collision points:
     /// - Lifetime - change of lifetime doesn't change type.
+    /// - Name - linker may merge identical names (e.g. same type from two versions of the same crate).
     ///
     ///```
     ///use type_traits::Type;
@@ -50,13 +124,112 @@ impl<T> Type<T> {
     ///   Box<dyn core::fmt::Debug + Send>,
     ///   Box<dyn core::fmt::Debug + Send + Sync>,
     ///   for<'a> fn(&'a (), &'a ()),
-    ///   fn(())
+    ///   fn(()),
+    ///   str, [u8], dyn core::fmt::Debug
     ///);
     ///```
     pub fn id() -> usize {
-        core::any::type_name::<T>().as_ptr() as usize
+        any::type_name::<T>().as_ptr() as usize
     }
 
+    #[inline(always)]
+    ///Returns type's name, as given by [type_name](https://doc.rust-lang.org/core/any/fn.type_name.html)
+    ///
+    ///Exact content of the name is not specified and may change between compiler versions.
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///assert_eq!(Type::<u8>::name(), "u8");
+    ///assert_eq!(Type::<Option<u8>>::name(), "core::option::Option<u8>");
+    ///```
+    pub fn name() -> &'static str {
+        any::type_name::<T>()
+    }
+
+    #[inline(always)]
+    ///Returns type's name with module path stripped from every path within name.
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///struct Foo;
+    ///
+    ///assert_eq!(Type::<Vec<Option<Foo>>>::short_name().to_string(), "Vec<Option<Foo>>");
+    ///assert_eq!(Type::<Result<Foo, std::io::Error>>::short_name().to_string(), "Result<Foo, Error>");
+    ///```
+    pub fn short_name() -> ShortName<'static> {
+        ShortName::new(Self::name())
+    }
+
+    #[inline(always)]
+    ///Returns whether type has `Drop` implementation with side effects
+    pub const fn needs_drop() -> bool {
+        mem::needs_drop::<T>()
+    }
+
+    #[inline(always)]
+    ///Returns kind of metadata within pointer to the type.
+    ///
+    ///Result is best-effort, see [Metadata::of](enum.Metadata.html#method.of) for details.
+    ///
+    ///```
+    ///use type_traits::{Type, Metadata};
+    ///
+    ///assert_eq!(Type::<u8>::metadata(), Metadata::None);
+    ///assert_eq!(Type::<str>::metadata(), Metadata::Length);
+    ///assert_eq!(Type::<[u8]>::metadata(), Metadata::Length);
+    ///assert_eq!(Type::<dyn core::fmt::Debug>::metadata(), Metadata::VTable);
+    ///```
+    pub fn metadata() -> Metadata {
+        Metadata::of::<T>()
+    }
+
+    #[inline(always)]
+    ///Returns size of the value
+    pub const fn size_of_val(val: &T) -> usize {
+        mem::size_of_val(val)
+    }
+
+    #[inline(always)]
+    ///Returns minimum alignment of the value
+    pub const fn align_of_val(val: &T) -> usize {
+        mem::align_of_val(val)
+    }
+
+    #[inline(always)]
+    ///Returns information about the value.
+    ///
+    ///Metadata kind is best-effort, see [Metadata::of](enum.Metadata.html#method.of) for details.
+    ///
+    ///```
+    ///use type_traits::{Type, Metadata};
+    ///
+    ///let text: &str = "text";
+    ///let info = Type::of_val(text);
+    ///assert_eq!(info.size(), 4);
+    ///assert_eq!(info.align(), 1);
+    ///assert_eq!(info.metadata(), Metadata::Length);
+    ///
+    ///let debug: &dyn core::fmt::Debug = &0u32;
+    ///let info = Type::of_val(debug);
+    ///assert_eq!(info.size(), 4);
+    ///assert_eq!(info.align(), 4);
+    ///assert_eq!(info.metadata(), Metadata::VTable);
+    ///
+    ///let info = Type::of_val(&0u16);
+    ///assert_eq!(info.size(), 2);
+    ///assert_eq!(info.metadata(), Metadata::None);
+    ///```
+    pub fn of_val(val: &T) -> ValueInfo {
+        ValueInfo::new(Self::size_of_val(val), Self::align_of_val(val), Self::metadata())
+    }
+}
+
+impl<T> Type<T> {
+    ///Maximum number of niche values detected by [niche_count](#method.niche_count)
+    pub const MAX_NICHE_PROBE: usize = 8;
+
     ///Returns object size
     #[inline(always)]
     pub const fn size() -> usize {
@@ -76,9 +249,197 @@ impl<T> Type<T> {
     }
 
     #[inline(always)]
-    ///Returns whether type has `Drop` implementation with side effects
-    pub const fn needs_drop() -> bool {
-        mem::needs_drop::<T>()
+    ///Returns whether type has niche, i.e. `Option<T>` is of the same size as `T`
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///assert!(Type::<&u8>::has_niche());
+    ///assert!(Type::<bool>::has_niche());
+    ///assert!(Type::<core::num::NonZeroU32>::has_niche());
+    ///assert!(!Type::<u32>::has_niche());
+    ///```
+    pub const fn has_niche() -> bool {
+        Type::<Option<T>>::size() == Self::size()
+    }
+
+    ///Returns estimate of number of niche values available within type.
+    ///
+    ///It is determined by number of nested `Option<T>` that are of the same size as `T`, hence
+    ///result saturates at `MAX_NICHE_PROBE`.
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///assert_eq!(Type::<u32>::niche_count(), 0);
+    ///assert_eq!(Type::<&u8>::niche_count(), 1);
+    ///assert_eq!(Type::<bool>::niche_count(), Type::<bool>::MAX_NICHE_PROBE);
+    ///```
+    pub const fn niche_count() -> usize {
+        macro_rules! probe {
+            ($count:expr; $typ:ty) => {
+                if Type::<$typ>::size() != Self::size() {
+                    return $count;
+                }
+            };
+        }
+
+        probe!(0; Option<T>);
+        probe!(1; Option<Option<T>>);
+        probe!(2; Option<Option<Option<T>>>);
+        probe!(3; Option<Option<Option<Option<T>>>>);
+        probe!(4; Option<Option<Option<Option<Option<T>>>>>);
+        probe!(5; Option<Option<Option<Option<Option<Option<T>>>>>>);
+        probe!(6; Option<Option<Option<Option<Option<Option<Option<T>>>>>>>);
+        probe!(7; Option<Option<Option<Option<Option<Option<Option<Option<T>>>>>>>>);
+        Self::MAX_NICHE_PROBE
+    }
+
+    #[inline(always)]
+    ///Returns type's memory layout
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///use core::alloc::Layout;
+    ///
+    ///const LAYOUT: Layout = Type::<u32>::layout();
+    ///assert_eq!(LAYOUT, Layout::new::<u32>());
+    ///```
+    pub const fn layout() -> alloc::Layout {
+        alloc::Layout::new::<T>()
+    }
+
+    #[inline(always)]
+    ///Returns maximum number of elements in array of `T`, which can be allocated.
+    ///
+    ///For ZST it is `usize::MAX`, otherwise array size cannot exceed `isize::MAX`
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///assert_eq!(Type::<u8>::max_array_len(), isize::MAX as usize);
+    ///assert_eq!(Type::<u32>::max_array_len(), isize::MAX as usize / 4);
+    ///assert_eq!(Type::<()>::max_array_len(), usize::MAX);
+    ///```
+    pub const fn max_array_len() -> usize {
+        match Self::size() {
+            0 => usize::MAX,
+            size => isize::MAX as usize / size,
+        }
+    }
+
+    ///Returns memory layout of array with `len` elements, if it doesn't overflow.
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///use core::alloc::Layout;
+    ///
+    ///assert_eq!(Type::<u32>::array_layout(4), Some(Layout::new::<[u32; 4]>()));
+    ///assert_eq!(Type::<u32>::array_layout(0), Some(Layout::new::<[u32; 0]>()));
+    ///assert_eq!(Type::<()>::array_layout(usize::MAX), Some(Layout::new::<()>()));
+    ///assert_eq!(Type::<u32>::array_layout(Type::<u32>::max_array_len() + 1), None);
+    ///```
+    pub const fn array_layout(len: usize) -> Option<alloc::Layout> {
+        if len > Self::max_array_len() {
+            return None;
+        }
+
+        match alloc::Layout::from_size_align(Self::size() * len, Self::align()) {
+            Ok(layout) => Some(layout),
+            Err(_) => None,
+        }
+    }
+
+}
+
+impl<T: ?Sized + 'static> Type<T> {
+    #[inline(always)]
+    ///Get unique type id, usable within const context.
+    ///
+    ///Unlike [id](#method.id) this is guaranteed to be unique for every distinct type within
+    ///single build, regardless of crate or codegen unit where it is evaluated.
+    ///
+    ///It is not stable across builds and compiler versions, hence must not be persisted.
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///use core::any::TypeId;
+    ///
+    ///const U8: TypeId = Type::<u8>::type_id();
+    ///
+    ///assert_eq!(U8, TypeId::of::<u8>());
+    ///assert_ne!(U8, Type::<i8>::type_id());
+    ///assert_ne!(Type::<Box<dyn core::fmt::Debug>>::type_id(), Type::<Box<dyn core::fmt::Debug + Send>>::type_id());
+    ///assert_ne!(Type::<str>::type_id(), Type::<[u8]>::type_id());
+    ///```
+    pub const fn type_id() -> any::TypeId {
+        any::TypeId::of::<T>()
+    }
+
+    #[inline(always)]
+    ///Returns whether `T` is the same type as `U`
+    ///
+    ///```
+    ///use type_traits::Type;
+    ///
+    ///type Handle = u32;
+    ///
+    ///assert!(Type::<Handle>::is::<u32>());
+    ///assert!(!Type::<Handle>::is::<i32>());
+    ///assert!(Type::<str>::is::<str>());
+    ///```
+    pub fn is<U: ?Sized + 'static>() -> bool {
+        Self::type_id() == Type::<U>::type_id()
+    }
+}
+
+impl<T: TypeLayout> Type<T> {
+    #[inline(always)]
+    ///Returns struct's fields, as described by [TypeLayout](trait.TypeLayout.html)
+    pub const fn fields() -> &'static [Field] {
+        T::FIELDS
+    }
+
+    ///Returns number of padding bytes within struct, including padding within its fields.
+    ///
+    ///Same as [TypeLayout::PADDING_BYTES](trait.TypeLayout.html#associatedconstant.PADDING_BYTES)
+    ///
+    ///```
+    ///use type_traits::{Type, TypeLayout};
+    ///
+    ///assert_eq!(Type::<u32>::padding_bytes(), 0);
+    ///
+    ///#[cfg(feature = "derive")]
+    ///{
+    ///    #[derive(TypeLayout)]
+    ///    #[repr(C)]
+    ///    struct Foo {
+    ///        a: u8,
+    ///        b: u32,
+    ///    }
+    ///
+    ///    #[derive(TypeLayout)]
+    ///    #[repr(C)]
+    ///    struct Bar {
+    ///        foo: Foo,
+    ///        c: [Foo; 2],
+    ///    }
+    ///
+    ///    const PADDING: usize = Type::<Foo>::padding_bytes();
+    ///    assert_eq!(PADDING, 3);
+    ///    assert_eq!(Type::<Bar>::padding_bytes(), 9);
+    ///}
+    ///```
+    pub const fn padding_bytes() -> usize {
+        T::PADDING_BYTES
+    }
+}
+
+impl<T: 'static> Type<T> {
+    #[inline(always)]
+    ///Returns type information as [TypeInfo](struct.TypeInfo.html)
+    pub const fn info() -> TypeInfo {
+        TypeInfo::of::<T>()
     }
 }
 
@@ -88,10 +449,29 @@ impl<T> Type<T> {
 ///used, hence on its own every constant within `Assert` would not produce compile error, even if
 ///you refer to concrete instance of `Assert`
 ///In order to perform assertion, you must use associated constant, otherwise generic constant is not evaluated.
+///
+///Assertions against concrete size and alignment are provided by separate types, parameterised by const generic:
+///[SizeIs](struct.SizeIs.html), [SizeAtMost](struct.SizeAtMost.html), [SizeAtLeast](struct.SizeAtLeast.html),
+///[AlignIs](struct.AlignIs.html), [AlignAtLeast](struct.AlignAtLeast.html) and [AlignAtMost](struct.AlignAtMost.html)
+///
+///Trait bounds are asserted by macros: [assert_impl](macro.assert_impl.html), [assert_not_impl](macro.assert_not_impl.html)
+///and [assert_obj_safe](macro.assert_obj_safe.html)
+///
+///On failure, message states violated property alongside with actual numbers, e.g.
+///`size_of::<T> (4) must be 0`, while compiler names concrete type in
+///``evaluation of `type_traits::Assert::<u32>::IS_ZST` failed``.
+///
+///It is also zero sized token with the same properties as [Type](struct.Type.html).
 #[repr(transparent)]
-pub struct Assert<T>(marker::PhantomData<T>);
+pub struct Assert<T>(Covariant<T>);
 
 impl<T> Assert<T> {
+    #[inline(always)]
+    ///Creates new token
+    pub const fn new() -> Self {
+        Self(marker::PhantomData)
+    }
+
     ///Asserts type requires no call `Drop::drop`
     ///
     ///This relies on `mem::needs_drop` which may or may not return correctly, but if it returns
@@ -109,7 +489,9 @@ impl<T> Assert<T> {
     ///
     ///test(0);
     ///```
-    pub const NO_NEED_DROP: () = assert!(!Type::<T>::needs_drop());
+    pub const NO_NEED_DROP: () = if Type::<T>::needs_drop() {
+        Message::new().text("T must not need drop").fail()
+    };
 
     ///Asserts type is not ZST.
     ///
@@ -124,7 +506,9 @@ impl<T> Assert<T> {
     ///
     ///test(0);
     ///```
-    pub const IS_NOT_ZST: () = assert!(!Type::<T>::is_zst());
+    pub const IS_NOT_ZST: () = if Type::<T>::is_zst() {
+        Message::new().value("size_of::<T>", Type::<T>::size()).text(" must not be 0").fail()
+    };
 
     ///Asserts type is ZST
     ///
@@ -139,7 +523,73 @@ impl<T> Assert<T> {
     ///
     ///test(());
     ///```
-    pub const IS_ZST: () = assert!(Type::<T>::is_zst());
+    pub const IS_ZST: () = if !Type::<T>::is_zst() {
+        Message::new().value("size_of::<T>", Type::<T>::size()).text(" must be 0").fail()
+    };
+
+    ///Asserts type has niche, i.e. `Option<T>` is of the same size as `T`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Assert;
+    ///
+    ///fn test<T>(input: T) -> Option<T> {
+    ///    let _ = Assert::<T>::HAS_NICHE;
+    ///    Some(input)
+    ///}
+    ///
+    ///test(core::num::NonZeroU8::new(1).unwrap());
+    ///```
+    pub const HAS_NICHE: () = if !Type::<T>::has_niche() {
+        Message::new().value("size_of::<Option<T>>", Type::<Option<T>>::size())
+                      .text(" must equal ").value("size_of::<T>", Type::<T>::size())
+                      .fail()
+    };
+
+    ///Asserts `Option<T>` is pointer sized, same as `T`.
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Assert;
+    ///
+    ///fn test<T>(input: T) -> Option<T> {
+    ///    let _ = Assert::<T>::IS_NULL_POINTER_OPTIMIZED;
+    ///    Some(input)
+    ///}
+    ///
+    ///test(Box::new(0u8));
+    ///test(&0u8);
+    ///```
+    pub const IS_NULL_POINTER_OPTIMIZED: () = if Type::<T>::size() != Type::<*const ()>::size() {
+        Message::new().value("size_of::<T>", Type::<T>::size())
+                      .text(" must equal ").value("size_of::<*const ()>", Type::<*const ()>::size())
+                      .fail()
+    } else if !Type::<T>::has_niche() {
+        Message::new().value("size_of::<Option<T>>", Type::<Option<T>>::size())
+                      .text(" must equal ").value("size_of::<T>", Type::<T>::size())
+                      .fail()
+    };
+}
+
+impl<T: TypeLayout> Assert<T> {
+    ///Asserts struct has no padding, including padding within its fields.
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::{Assert, TypeLayout};
+    ///
+    ///fn hash<T: TypeLayout>(input: &T) {
+    ///    let _ = Assert::<T>::HAS_NO_PADDING;
+    ///}
+    ///
+    ///hash(&0u32);
+    ///```
+    pub const HAS_NO_PADDING: () = if Type::<T>::padding_bytes() != 0 {
+        Message::new().value("padding bytes of T", Type::<T>::padding_bytes()).text(" must be 0").fail()
+    };
 }
 
 ///Static assertion helper for pair of types
@@ -148,10 +598,21 @@ impl<T> Assert<T> {
 ///used, hence on its own every constant within `Assert` would not produce compile error, even if
 ///you refer to concrete instance of `Assert`
 ///In order to perform assertion, you must use associated constant, otherwise generic constant is not evaluated.
+///
+///On failure, message states violated property alongside with actual numbers, e.g.
+///`size_of::<L> (24) must equal size_of::<R> (16)`.
+///
+///It is also zero sized token with the same properties as [Type](struct.Type.html).
 #[repr(transparent)]
-pub struct Assert2<L, R>(marker::PhantomData<(L, R)>);
+pub struct Assert2<L, R>(Covariant<(L, R)>);
 
 impl<L, R> Assert2<L, R> {
+    #[inline(always)]
+    ///Creates new token
+    pub const fn new() -> Self {
+        Self(marker::PhantomData)
+    }
+
     ///Asserts both types are of the same size
     ///
     ///## Usage
@@ -166,7 +627,11 @@ impl<L, R> Assert2<L, R> {
     ///
     ///test(0u8, false);
     ///```
-    pub const IS_SAME_SIZE: () = assert!(Type::<L>::size() == Type::<R>::size());
+    pub const IS_SAME_SIZE: () = if Type::<L>::size() != Type::<R>::size() {
+        Message::new().value("size_of::<L>", Type::<L>::size())
+                      .text(" must equal ").value("size_of::<R>", Type::<R>::size())
+                      .fail()
+    };
 
     ///Asserts both types are of the minimum alignment.
     ///
@@ -182,7 +647,11 @@ impl<L, R> Assert2<L, R> {
     ///
     ///test(0u8, false);
     ///```
-    pub const IS_SAME_ALIGN: () = assert!(Type::<L>::align() == Type::<R>::align());
+    pub const IS_SAME_ALIGN: () = if Type::<L>::align() != Type::<R>::align() {
+        Message::new().value("align_of::<L>", Type::<L>::align())
+                      .text(" must equal ").value("align_of::<R>", Type::<R>::align())
+                      .fail()
+    };
 
     ///Asserts that `L` size is greater or equal to `R`
     ///
@@ -199,7 +668,11 @@ impl<L, R> Assert2<L, R> {
     ///
     ///test(0u32, false);
     ///```
-    pub const IS_LEFT_SIZE_GREATER_OR_EQUAL: () = assert!(Type::<L>::size() >= Type::<R>::size());
+    pub const IS_LEFT_SIZE_GREATER_OR_EQUAL: () = if Type::<L>::size() < Type::<R>::size() {
+        Message::new().value("size_of::<L>", Type::<L>::size())
+                      .text(" must be greater or equal to ").value("size_of::<R>", Type::<R>::size())
+                      .fail()
+    };
 
     ///Asserts that `L` size is less that of `R`
     ///
@@ -216,7 +689,32 @@ impl<L, R> Assert2<L, R> {
     ///
     ///test(false, 0u32);
     ///```
-    pub const IS_LEFT_SIZE_LESS: () = assert!(Type::<L>::size() < Type::<R>::size());
+    pub const IS_LEFT_SIZE_LESS: () = if Type::<L>::size() >= Type::<R>::size() {
+        Message::new().value("size_of::<L>", Type::<L>::size())
+                      .text(" must be less than ").value("size_of::<R>", Type::<R>::size())
+                      .fail()
+    };
+
+    ///Asserts that `L` size is multiple of non-zero `R` size
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::{Type, Assert2};
+    ///
+    ///fn test<T, O>(input: T, default: O) -> O {
+    ///    assert_eq!(Type::<T>::size() % Type::<O>::size(), 0);
+    ///    let _ = Assert2::<T, O>::IS_LEFT_SIZE_MULTIPLE;
+    ///    default
+    ///}
+    ///
+    ///test([0u8; 6], 0u16);
+    ///```
+    pub const IS_LEFT_SIZE_MULTIPLE: () = if Type::<R>::size() == 0 || !Type::<L>::size().is_multiple_of(Type::<R>::size()) {
+        Message::new().value("size_of::<L>", Type::<L>::size())
+                      .text(" must be multiple of non-zero ").value("size_of::<R>", Type::<R>::size())
+                      .fail()
+    };
 
     ///Asserts that `L` minimum alignment is greater or equal to `R`
     ///
@@ -233,7 +731,11 @@ impl<L, R> Assert2<L, R> {
     ///
     ///test(0u32, false);
     ///```
-    pub const IS_LEFT_ALIGN_GREATER_OR_EQUAL: () = assert!(Type::<L>::align() >= Type::<R>::align());
+    pub const IS_LEFT_ALIGN_GREATER_OR_EQUAL: () = if Type::<L>::align() < Type::<R>::align() {
+        Message::new().value("align_of::<L>", Type::<L>::align())
+                      .text(" must be greater or equal to ").value("align_of::<R>", Type::<R>::align())
+                      .fail()
+    };
 
     ///Asserts that `L` minimum alignment is less that of `R`
     ///
@@ -250,5 +752,33 @@ impl<L, R> Assert2<L, R> {
     ///
     ///test(0u8, 0u32);
     ///```
-    pub const IS_LEFT_ALIGN_LESS: () = assert!(Type::<L>::align() < Type::<R>::align());
+    pub const IS_LEFT_ALIGN_LESS: () = if Type::<L>::align() >= Type::<R>::align() {
+        Message::new().value("align_of::<L>", Type::<L>::align())
+                      .text(" must be less than ").value("align_of::<R>", Type::<R>::align())
+                      .fail()
+    };
+}
+
+impl<T> Assert2<T, T> {
+    ///Asserts both types are the same.
+    ///
+    ///Unlike other assertions, it is verified during type checking, hence it is available only
+    ///when compiler can prove types to be the same. Generic code should accept [TypeEq](struct.TypeEq.html) instead.
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Assert2;
+    ///
+    ///type Handle = u32;
+    ///
+    ///let _ = Assert2::<Handle, u32>::IS_SAME_TYPE;
+    ///```
+    ///
+    ///```compile_fail
+    ///use type_traits::Assert2;
+    ///
+    ///let _ = Assert2::<u32, i32>::IS_SAME_TYPE;
+    ///```
+    pub const IS_SAME_TYPE: () = ();
 }
@@ -0,0 +1,307 @@
+//!Type lists
+
+use crate::{Type, Assert, TypeInfo, Covariant};
+use crate::msg::Message;
+
+use core::marker;
+
+const fn max(values: &[usize], default: usize) -> usize {
+    let mut result = default;
+    let mut idx = 0;
+    while idx < values.len() {
+        if values[idx] > result {
+            result = values[idx];
+        }
+        idx += 1;
+    }
+    result
+}
+
+const fn sum(values: &[usize]) -> usize {
+    let mut result = 0;
+    let mut idx = 0;
+    while idx < values.len() {
+        result += values[idx];
+        idx += 1;
+    }
+    result
+}
+
+const fn any(values: &[bool]) -> bool {
+    let mut idx = 0;
+    while idx < values.len() {
+        if values[idx] {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
+///List of types, implemented for tuples up to 16 elements.
+///
+///## Usage
+///
+///```
+///use type_traits::{TypeInfo, TypeList};
+///
+///type Payloads = (u8, u32, String);
+///
+///assert_eq!(Payloads::LEN, 3);
+///assert_eq!(Payloads::SIZES, [1, 4, core::mem::size_of::<String>()]);
+///assert_eq!(Payloads::infos()[1], TypeInfo::of::<u32>());
+///assert_eq!(Payloads::MAX_SIZE, core::mem::size_of::<String>());
+///assert_eq!(Payloads::MAX_ALIGN, core::mem::align_of::<String>());
+///assert_eq!(Payloads::TOTAL_SIZE, 1 + 4 + core::mem::size_of::<String>());
+///assert!(Payloads::ANY_NEEDS_DROP);
+///assert!(!<(u8, u32)>::ANY_NEEDS_DROP);
+///```
+pub trait TypeList {
+    ///Number of types
+    const LEN: usize;
+    ///Size of each type
+    const SIZES: &'static [usize];
+    ///Minimum alignment of each type
+    const ALIGNS: &'static [usize];
+    ///Whether each type has `Drop` implementation with side effects
+    const NEEDS_DROP: &'static [bool];
+
+    ///Maximum size among types
+    const MAX_SIZE: usize = max(Self::SIZES, 0);
+    ///Maximum alignment among types
+    const MAX_ALIGN: usize = max(Self::ALIGNS, 1);
+    ///Sum of sizes of all types
+    const TOTAL_SIZE: usize = sum(Self::SIZES);
+    ///Whether any of types has `Drop` implementation with side effects
+    const ANY_NEEDS_DROP: bool = any(Self::NEEDS_DROP);
+
+    ///Returns information of each type
+    fn infos() -> &'static [TypeInfo] where Self: 'static;
+}
+
+macro_rules! impl_type_list {
+    ($len:expr; $($typ:ident),*) => {
+        impl<$($typ),*> TypeList for ($($typ,)*) {
+            const LEN: usize = $len;
+            const SIZES: &'static [usize] = &[$(Type::<$typ>::size()),*];
+            const ALIGNS: &'static [usize] = &[$(Type::<$typ>::align()),*];
+            const NEEDS_DROP: &'static [bool] = &[$(Type::<$typ>::needs_drop()),*];
+
+            #[inline(always)]
+            fn infos() -> &'static [TypeInfo] where Self: 'static {
+                const {
+                    &[$(TypeInfo::of::<$typ>()),*]
+                }
+            }
+        }
+    };
+}
+
+impl_type_list!(0;);
+impl_type_list!(1; T1);
+impl_type_list!(2; T1, T2);
+impl_type_list!(3; T1, T2, T3);
+impl_type_list!(4; T1, T2, T3, T4);
+impl_type_list!(5; T1, T2, T3, T4, T5);
+impl_type_list!(6; T1, T2, T3, T4, T5, T6);
+impl_type_list!(7; T1, T2, T3, T4, T5, T6, T7);
+impl_type_list!(8; T1, T2, T3, T4, T5, T6, T7, T8);
+impl_type_list!(9; T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_type_list!(10; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_type_list!(11; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_type_list!(12; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+impl_type_list!(13; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
+impl_type_list!(14; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
+impl_type_list!(15; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
+impl_type_list!(16; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16);
+
+impl<T: TypeList> Assert<T> {
+    ///Asserts all types within list are of the same size
+    ///
+    ///Same as [AssertAll::SAME_SIZE](struct.AssertAll.html#associatedconstant.SAME_SIZE)
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Assert;
+    ///
+    ///let _ = Assert::<(u32, i32, f32, char)>::ALL_SAME_SIZE;
+    ///```
+    pub const ALL_SAME_SIZE: () = AssertAll::<T>::SAME_SIZE;
+
+    ///Asserts all types within list are of the same minimum alignment
+    ///
+    ///Same as [AssertAll::SAME_ALIGN](struct.AssertAll.html#associatedconstant.SAME_ALIGN)
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Assert;
+    ///
+    ///let _ = Assert::<(u8, i8, bool)>::ALL_SAME_ALIGN;
+    ///```
+    pub const ALL_SAME_ALIGN: () = AssertAll::<T>::SAME_ALIGN;
+
+    ///Asserts none of types within list requires call to `Drop::drop`
+    ///
+    ///Same as [AssertAll::NONE_NEED_DROP](struct.AssertAll.html#associatedconstant.NONE_NEED_DROP)
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::Assert;
+    ///
+    ///fn send<A, B, C>(a: A, b: B, c: C) {
+    ///    let _ = Assert::<(A, B, C)>::NONE_NEED_DROP;
+    ///}
+    ///```
+    pub const NONE_NEED_DROP: () = AssertAll::<T>::NONE_NEED_DROP;
+}
+
+///Static assertion helper for list of types
+///
+///This assertion relies on the fact that generic code is always compiled when generic is actually
+///used, hence on its own every constant within `AssertAll` would not produce compile error, even if
+///you refer to concrete instance of `AssertAll`
+///In order to perform assertion, you must use associated constant, otherwise generic constant is not evaluated.
+///
+///On failure, message names index of the element within list, that violated assertion.
+///
+///It is also zero sized token with the same properties as [Type](struct.Type.html).
+#[repr(transparent)]
+pub struct AssertAll<T>(Covariant<T>);
+
+impl<T> AssertAll<T> {
+    #[inline(always)]
+    ///Creates new token
+    pub const fn new() -> Self {
+        Self(marker::PhantomData)
+    }
+}
+
+impl<T: TypeList> AssertAll<T> {
+    ///Asserts all types within list are of the same size
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::AssertAll;
+    ///
+    ///fn test<A, B, C, D>(a: A, b: B, c: C, d: D) {
+    ///    let _ = AssertAll::<(A, B, C, D)>::SAME_SIZE;
+    ///}
+    ///
+    ///test(0u32, 0i32, 0f32, 'a');
+    ///```
+    ///
+    ///```compile_fail
+    ///use type_traits::AssertAll;
+    ///
+    ///let _ = AssertAll::<(u32, i32, f64, char)>::SAME_SIZE;
+    ///```
+    pub const SAME_SIZE: () = {
+        let mut idx = 1;
+        while idx < T::LEN {
+            if T::SIZES[idx] != T::SIZES[0] {
+                Message::new().text("size of element #").num(idx).text(" (").num(T::SIZES[idx])
+                              .text(") must equal size of element #0 (").num(T::SIZES[0]).text(")")
+                              .fail()
+            }
+            idx += 1;
+        }
+    };
+
+    ///Asserts all types within list are of the same minimum alignment
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::AssertAll;
+    ///
+    ///fn test<A, B, C>(a: A, b: B, c: C) {
+    ///    let _ = AssertAll::<(A, B, C)>::SAME_ALIGN;
+    ///}
+    ///
+    ///test(0u8, false, 0i8);
+    ///```
+    pub const SAME_ALIGN: () = {
+        let mut idx = 1;
+        while idx < T::LEN {
+            if T::ALIGNS[idx] != T::ALIGNS[0] {
+                Message::new().text("alignment of element #").num(idx).text(" (").num(T::ALIGNS[idx])
+                              .text(") must equal alignment of element #0 (").num(T::ALIGNS[0]).text(")")
+                              .fail()
+            }
+            idx += 1;
+        }
+    };
+
+    ///Asserts none of types within list requires call to `Drop::drop`
+    ///
+    ///## Usage
+    ///
+    ///```
+    ///use type_traits::AssertAll;
+    ///
+    ///fn test<A, B, C>(a: A, b: B, c: C) {
+    ///    let _ = AssertAll::<(A, B, C)>::NONE_NEED_DROP;
+    ///}
+    ///
+    ///test(0u8, false, "text");
+    ///```
+    ///
+    ///```compile_fail
+    ///use type_traits::AssertAll;
+    ///
+    ///let _ = AssertAll::<(u8, String)>::NONE_NEED_DROP;
+    ///```
+    pub const NONE_NEED_DROP: () = {
+        let mut idx = 0;
+        while idx < T::LEN {
+            if T::NEEDS_DROP[idx] {
+                Message::new().text("element #").num(idx).text(" must not need drop").fail()
+            }
+            idx += 1;
+        }
+    };
+}
+
+///Asserts every type within list has size less or equal to `N`
+///
+///This assertion relies on the fact that generic code is always compiled when generic is actually
+///used, hence in order to perform assertion, you must use associated constant `ASSERT`.
+///
+///## Usage
+///
+///```
+///use type_traits::AllFitIn;
+///
+///fn test<A, B, C>(a: A, b: B, c: C) {
+///    let _ = AllFitIn::<(A, B, C), 8>::ASSERT;
+///}
+///
+///test(0u8, 0u64, [0u16; 4]);
+///```
+///
+///```compile_fail
+///use type_traits::AllFitIn;
+///
+///let _ = AllFitIn::<(u8, u128), 8>::ASSERT;
+///```
+#[repr(transparent)]
+pub struct AllFitIn<T, const N: usize>(marker::PhantomData<T>);
+
+impl<T: TypeList, const N: usize> AllFitIn<T, N> {
+    ///Performs assertion
+    pub const ASSERT: () = {
+        let mut idx = 0;
+        while idx < T::LEN {
+            if T::SIZES[idx] > N {
+                Message::new().text("size of element #").num(idx).text(" (").num(T::SIZES[idx])
+                              .text(") must not exceed ").num(N)
+                              .fail()
+            }
+            idx += 1;
+        }
+    };
+}
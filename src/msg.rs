@@ -0,0 +1,104 @@
+//!Const formatting of assertion messages
+
+const CAPACITY: usize = 256;
+
+///Message buffer, that can be built in const context.
+///
+///Message is truncated when it exceeds capacity.
+pub(crate) struct Message {
+    buf: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Message {
+    #[inline(always)]
+    pub(crate) const fn new() -> Self {
+        Self {
+            buf: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub(crate) const fn text(mut self, text: &str) -> Self {
+        let text = text.as_bytes();
+        let mut idx = 0;
+        while idx < text.len() && self.len < CAPACITY {
+            self.buf[self.len] = text[idx];
+            self.len += 1;
+            idx += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn num(mut self, mut num: usize) -> Self {
+        let mut digits = [0u8; 20];
+        let mut len = 0;
+        loop {
+            digits[len] = b'0' + (num % 10) as u8;
+            len += 1;
+            num /= 10;
+            if num == 0 {
+                break;
+            }
+        }
+
+        while len > 0 && self.len < CAPACITY {
+            len -= 1;
+            self.buf[self.len] = digits[len];
+            self.len += 1;
+        }
+        self
+    }
+
+    ///Appends `<name> (<value>)`
+    pub(crate) const fn value(self, name: &str, value: usize) -> Self {
+        self.text(name).text(" (").num(value).text(")")
+    }
+
+    ///Panics with message, intended to be used within assertions.
+    pub(crate) const fn fail(self) -> ! {
+        panic!("{}", self.as_str())
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        let (text, _) = self.buf.split_at(self.len);
+        match core::str::from_utf8(text) {
+            Ok(text) => text,
+            //Text is only truncated on overflow
+            Err(error) => match core::str::from_utf8(text.split_at(error.valid_up_to()).0) {
+                Ok(text) => text,
+                Err(_) => "",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{Message, CAPACITY};
+    use std::string::ToString;
+
+    #[test]
+    fn should_format_message() {
+        let msg = Message::new().value("size_of::<L>", 24).text(" must equal ").value("size_of::<R>", 16);
+        assert_eq!(msg.as_str(), "size_of::<L> (24) must equal size_of::<R> (16)");
+    }
+
+    #[test]
+    fn should_format_number_bounds() {
+        assert_eq!(Message::new().num(0).as_str(), "0");
+        assert_eq!(Message::new().num(usize::MAX).as_str(), usize::MAX.to_string());
+    }
+
+    #[test]
+    fn should_truncate_at_char_boundary() {
+        let msg = Message::new().text(&"a".repeat(CAPACITY - 1)).text("ё");
+        assert_eq!(msg.len, CAPACITY);
+        assert_eq!(msg.as_str(), "a".repeat(CAPACITY - 1));
+
+        let msg = Message::new().text(&"ё".repeat(CAPACITY)).num(1);
+        assert_eq!(msg.as_str(), "ё".repeat(CAPACITY / 2));
+    }
+}
@@ -0,0 +1,74 @@
+//!Type name utilities
+
+use core::fmt;
+
+#[inline(always)]
+const fn is_path_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == ':' || ch == '{' || ch == '}' || !ch.is_ascii()
+}
+
+///Type's name without module paths.
+///
+///Module path is stripped from every path within name, including generic arguments.
+///
+///## Usage
+///
+///```
+///use type_traits::ShortName;
+///
+///let name = ShortName::new("alloc::vec::Vec<core::option::Option<my::Foo>>");
+///assert_eq!(name.to_string(), "Vec<Option<Foo>>");
+///
+///let name = ShortName::new("alloc::boxed::Box<dyn core::fmt::Debug + core::marker::Send>");
+///assert_eq!(name.to_string(), "Box<dyn Debug + Send>");
+///
+///let name = ShortName::new("(u8, [my::Foo; 2], &str)");
+///assert_eq!(name.to_string(), "(u8, [Foo; 2], &str)");
+///```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ShortName<'a>(&'a str);
+
+impl<'a> ShortName<'a> {
+    #[inline(always)]
+    ///Creates new instance from full type name
+    pub const fn new(name: &'a str) -> Self {
+        Self(name)
+    }
+
+    #[inline(always)]
+    ///Returns full type name
+    pub const fn full(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl fmt::Display for ShortName<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut name = self.0;
+
+        while !name.is_empty() {
+            let path_len = name.find(|ch| !is_path_char(ch)).unwrap_or(name.len());
+            let (path, rest) = name.split_at(path_len);
+            let path = match path.rfind("::") {
+                Some(idx) => &path[idx + 2..],
+                None => path,
+            };
+            fmt.write_str(path)?;
+
+            let delim_len = rest.find(is_path_char).unwrap_or(rest.len());
+            let (delim, rest) = rest.split_at(delim_len);
+            fmt.write_str(delim)?;
+
+            name = rest;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for ShortName<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
@@ -0,0 +1,390 @@
+//!Type erasure for non-static types
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use crate::Type;
+
+use core::{any, fmt, marker};
+
+///Type, that can be identified regardless of its lifetime `'a`.
+///
+///Use `#[derive(Tid)]` with `derive` feature to implement it.
+///
+///## Safety
+///
+///`Static` must be `Self` with lifetime `'a` replaced by `'static`, and `'a` must be the only
+///lifetime of `Self`. Otherwise erasure would allow to extend lifetime.
+///
+///## Usage
+///
+///```
+///use type_traits::{Tid, AnyRef};
+///
+///struct Request<'a> {
+///    path: &'a str,
+///}
+///
+///unsafe impl<'a> Tid<'a> for Request<'a> {
+///    type Static = Request<'static>;
+///}
+///
+///let path = String::from("/index");
+///let request = Request { path: &path };
+///let context = AnyRef::new(&request);
+///assert_eq!(context.downcast_ref::<Request>().unwrap().path, "/index");
+///assert!(context.downcast_ref::<&str>().is_none());
+///```
+///
+///`Static` must be `'static`, hence it cannot keep lifetime of `Self`:
+///
+///```compile_fail
+///use type_traits::Tid;
+///
+///struct Request<'a> {
+///    path: &'a str,
+///}
+///
+///unsafe impl<'a> Tid<'a> for Request<'a> {
+///    type Static = Request<'a>;
+///}
+///```
+pub unsafe trait Tid<'a>: 'a {
+    ///`Self` with lifetime `'a` replaced by `'static`
+    type Static: ?Sized + 'static;
+}
+
+#[inline(always)]
+fn tid<'a, T: ?Sized + Tid<'a>>() -> any::TypeId {
+    Type::<T::Static>::type_id()
+}
+
+//Erased types must be invariant over `'a`, otherwise it would be possible to downcast into type with shorter lifetime
+type Invariant<'a> = marker::PhantomData<fn(&'a ()) -> &'a ()>;
+
+macro_rules! impl_static_tid {
+    ($($typ:ty),+) => {
+        $(
+            unsafe impl<'a> Tid<'a> for $typ {
+                type Static = $typ;
+            }
+        )+
+    };
+}
+
+impl_static_tid!(
+    (), bool, char, str,
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+    f32, f64
+);
+
+unsafe impl<'a, T: ?Sized + Tid<'a>> Tid<'a> for &'a T {
+    type Static = &'static T::Static;
+}
+
+unsafe impl<'a, T: ?Sized + Tid<'a>> Tid<'a> for &'a mut T {
+    type Static = &'static mut T::Static;
+}
+
+unsafe impl<'a, T: Tid<'a>> Tid<'a> for [T] where T::Static: Sized {
+    type Static = [T::Static];
+}
+
+unsafe impl<'a, T: Tid<'a>, const N: usize> Tid<'a> for [T; N] where T::Static: Sized {
+    type Static = [T::Static; N];
+}
+
+unsafe impl<'a, T: Tid<'a>> Tid<'a> for Option<T> where T::Static: Sized {
+    type Static = Option<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<'a> Tid<'a> for alloc::string::String {
+    type Static = alloc::string::String;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<'a, T: Tid<'a>> Tid<'a> for alloc::vec::Vec<T> where T::Static: Sized {
+    type Static = alloc::vec::Vec<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<'a, T: ?Sized + Tid<'a>> Tid<'a> for alloc::boxed::Box<T> {
+    type Static = alloc::boxed::Box<T::Static>;
+}
+
+///Type-erased reference, that can be downcast to original type with lifetime `'a`.
+///
+///Reference, returned by downcast, cannot outlive original borrow:
+///
+///```compile_fail
+///use type_traits::AnyRef;
+///
+///let leaked: &u32;
+///{
+///    let value = 1u32;
+///    let erased = AnyRef::new(&value);
+///    leaked = erased.downcast_ref::<u32>().unwrap();
+///}
+///assert_eq!(*leaked, 1);
+///```
+///
+///Neither it is possible to downcast into type with longer lifetime:
+///
+///```compile_fail
+///use type_traits::AnyRef;
+///
+///fn extend<'short>(erased: AnyRef<'short>) -> Option<&'short &'static str> {
+///    erased.downcast_ref::<&'static str>()
+///}
+///```
+#[derive(Copy, Clone)]
+pub struct AnyRef<'a> {
+    id: any::TypeId,
+    ptr: *const (),
+    _lifetime: Invariant<'a>,
+}
+
+impl<'a> AnyRef<'a> {
+    #[inline(always)]
+    ///Erases type of reference
+    pub fn new<T: Tid<'a>>(value: &'a T) -> Self {
+        Self {
+            id: tid::<T>(),
+            ptr: value as *const T as *const (),
+            _lifetime: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns type id of original type, with lifetime `'static`
+    pub fn type_id(&self) -> any::TypeId {
+        self.id
+    }
+
+    #[inline(always)]
+    ///Returns whether original type is `T`
+    pub fn is<T: Tid<'a>>(&self) -> bool {
+        self.id == tid::<T>()
+    }
+
+    #[inline]
+    ///Returns reference to original type, if it is `T`
+    pub fn downcast_ref<T: Tid<'a>>(&self) -> Option<&'a T> {
+        match self.is::<T>() {
+            //Safety: pointer is created from `&'a T`
+            true => Some(unsafe { &*(self.ptr as *const T) }),
+            false => None,
+        }
+    }
+}
+
+///Type-erased mutable reference, that can be downcast to original type with lifetime `'a`.
+///
+///```
+///use type_traits::AnyMut;
+///
+///let mut value = 1u32;
+///let mut erased = AnyMut::new(&mut value);
+///assert!(erased.downcast_mut::<i32>().is_none());
+///*erased.downcast_mut::<u32>().unwrap() += 1;
+///let erased = erased.downcast::<u8>().unwrap_err();
+///*erased.downcast::<u32>().unwrap() += 1;
+///assert_eq!(value, 3);
+///```
+///
+///It is invariant over `'a`, otherwise it would be possible to store short-lived reference into long-lived one:
+///
+///```compile_fail
+///use type_traits::AnyMut;
+///
+///fn shrink<'short>(erased: AnyMut<'static>) -> AnyMut<'short> {
+///    erased
+///}
+///```
+pub struct AnyMut<'a> {
+    id: any::TypeId,
+    ptr: *mut (),
+    _lifetime: Invariant<'a>,
+}
+
+impl<'a> AnyMut<'a> {
+    #[inline(always)]
+    ///Erases type of reference
+    pub fn new<T: Tid<'a>>(value: &'a mut T) -> Self {
+        Self {
+            id: tid::<T>(),
+            ptr: value as *mut T as *mut (),
+            _lifetime: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns type id of original type, with lifetime `'static`
+    pub fn type_id(&self) -> any::TypeId {
+        self.id
+    }
+
+    #[inline(always)]
+    ///Returns whether original type is `T`
+    pub fn is<T: Tid<'a>>(&self) -> bool {
+        self.id == tid::<T>()
+    }
+
+    #[inline]
+    ///Returns reference to original type, if it is `T`
+    pub fn downcast_ref<T: Tid<'a>>(&self) -> Option<&T> {
+        match self.is::<T>() {
+            //Safety: pointer is created from `&'a mut T`
+            true => Some(unsafe { &*(self.ptr as *const T) }),
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Returns mutable reference to original type, if it is `T`
+    pub fn downcast_mut<T: Tid<'a>>(&mut self) -> Option<&mut T> {
+        match self.is::<T>() {
+            //Safety: pointer is created from `&'a mut T`
+            true => Some(unsafe { &mut *(self.ptr as *mut T) }),
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Converts into original reference, if it is `T`
+    pub fn downcast<T: Tid<'a>>(self) -> Result<&'a mut T, Self> {
+        match self.is::<T>() {
+            //Safety: pointer is created from `&'a mut T`
+            true => Ok(unsafe { &mut *(self.ptr as *mut T) }),
+            false => Err(self),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Type-erased box, that can be downcast to original type with lifetime `'a`.
+///
+///```
+///# #[cfg(feature = "alloc")]
+///# {
+///use type_traits::AnyBox;
+///
+///let text = String::from("text");
+///let erased = AnyBox::new(vec![text.as_str()]);
+///assert!(erased.is::<Vec<&str>>());
+///assert_eq!(erased.downcast_ref::<Vec<&str>>().unwrap(), &["text"]);
+///
+///let erased = erased.downcast::<Vec<String>>().unwrap_err();
+///let texts = erased.downcast::<Vec<&str>>().unwrap();
+///assert_eq!(*texts, ["text"]);
+///# }
+///```
+pub struct AnyBox<'a> {
+    id: any::TypeId,
+    ptr: *mut (),
+    drop: unsafe fn(*mut ()),
+    _lifetime: Invariant<'a>,
+}
+
+#[cfg(feature = "alloc")]
+unsafe fn drop_box<T>(ptr: *mut ()) {
+    drop(alloc::boxed::Box::from_raw(ptr as *mut T));
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> AnyBox<'a> {
+    #[inline]
+    ///Erases type of value
+    pub fn new<T: Tid<'a>>(value: T) -> Self {
+        Self::from_box(alloc::boxed::Box::new(value))
+    }
+
+    #[inline]
+    ///Erases type of boxed value
+    pub fn from_box<T: Tid<'a>>(value: alloc::boxed::Box<T>) -> Self {
+        Self {
+            id: tid::<T>(),
+            ptr: alloc::boxed::Box::into_raw(value) as *mut (),
+            drop: drop_box::<T>,
+            _lifetime: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns type id of original type, with lifetime `'static`
+    pub fn type_id(&self) -> any::TypeId {
+        self.id
+    }
+
+    #[inline(always)]
+    ///Returns whether original type is `T`
+    pub fn is<T: Tid<'a>>(&self) -> bool {
+        self.id == tid::<T>()
+    }
+
+    #[inline]
+    ///Returns reference to original type, if it is `T`
+    pub fn downcast_ref<T: Tid<'a>>(&self) -> Option<&T> {
+        match self.is::<T>() {
+            //Safety: pointer is created from `Box<T>`
+            true => Some(unsafe { &*(self.ptr as *const T) }),
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Returns mutable reference to original type, if it is `T`
+    pub fn downcast_mut<T: Tid<'a>>(&mut self) -> Option<&mut T> {
+        match self.is::<T>() {
+            //Safety: pointer is created from `Box<T>`
+            true => Some(unsafe { &mut *(self.ptr as *mut T) }),
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Converts into original box, if it is `T`
+    pub fn downcast<T: Tid<'a>>(self) -> Result<alloc::boxed::Box<T>, Self> {
+        match self.is::<T>() {
+            true => {
+                let this = core::mem::ManuallyDrop::new(self);
+                //Safety: pointer is created from `Box<T>`
+                Ok(unsafe { alloc::boxed::Box::from_raw(this.ptr as *mut T) })
+            },
+            false => Err(self),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for AnyBox<'_> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe {
+            (self.drop)(self.ptr)
+        }
+    }
+}
+
+impl fmt::Debug for AnyRef<'_> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AnyRef").field("type_id", &self.id).finish()
+    }
+}
+
+impl fmt::Debug for AnyMut<'_> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AnyMut").field("type_id", &self.id).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for AnyBox<'_> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AnyBox").field("type_id", &self.id).finish()
+    }
+}
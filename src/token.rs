@@ -0,0 +1,134 @@
+//!Trait implementations of type tokens
+
+use crate::{Type, Assert, Assert2, AssertAll};
+
+use core::{cmp, fmt, hash};
+
+macro_rules! impl_token {
+    ([$($decl:tt)+] $token:ident<$($param:ident),+>) => {
+        impl<$($decl)+> Clone for $token<$($param),+> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<$($decl)+> Copy for $token<$($param),+> {}
+
+        impl<$($decl)+> Default for $token<$($param),+> {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<$($decl)+> Eq for $token<$($param),+> where $($param: 'static),+ {}
+
+        impl<$($decl)+> Ord for $token<$($param),+> where $($param: 'static),+ {
+            #[inline(always)]
+            fn cmp(&self, _: &Self) -> cmp::Ordering {
+                cmp::Ordering::Equal
+            }
+        }
+
+        impl<$($decl)+> hash::Hash for $token<$($param),+> where $($param: 'static),+ {
+            #[inline(always)]
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                $(
+                    Type::<$param>::type_id().hash(state);
+                )+
+            }
+        }
+    };
+}
+
+impl_token!([T: ?Sized] Type<T>);
+impl_token!([T] Assert<T>);
+impl_token!([L, R] Assert2<L, R>);
+impl_token!([T] AssertAll<T>);
+
+impl<T: ?Sized + 'static, U: ?Sized + 'static> PartialEq<Type<U>> for Type<T> {
+    #[inline(always)]
+    fn eq(&self, _: &Type<U>) -> bool {
+        Type::<T>::is::<U>()
+    }
+}
+
+impl<T: ?Sized + 'static, U: ?Sized + 'static> PartialOrd<Type<U>> for Type<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, _: &Type<U>) -> Option<cmp::Ordering> {
+        Type::<T>::type_id().partial_cmp(&Type::<U>::type_id())
+    }
+}
+
+impl<T: 'static, U: 'static> PartialEq<Assert<U>> for Assert<T> {
+    #[inline(always)]
+    fn eq(&self, _: &Assert<U>) -> bool {
+        Type::<T>::is::<U>()
+    }
+}
+
+impl<T: 'static, U: 'static> PartialOrd<Assert<U>> for Assert<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, _: &Assert<U>) -> Option<cmp::Ordering> {
+        Type::<T>::type_id().partial_cmp(&Type::<U>::type_id())
+    }
+}
+
+impl<T: 'static, U: 'static> PartialEq<AssertAll<U>> for AssertAll<T> {
+    #[inline(always)]
+    fn eq(&self, _: &AssertAll<U>) -> bool {
+        Type::<T>::is::<U>()
+    }
+}
+
+impl<T: 'static, U: 'static> PartialOrd<AssertAll<U>> for AssertAll<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, _: &AssertAll<U>) -> Option<cmp::Ordering> {
+        Type::<T>::type_id().partial_cmp(&Type::<U>::type_id())
+    }
+}
+
+impl<L: 'static, R: 'static, OL: 'static, OR: 'static> PartialEq<Assert2<OL, OR>> for Assert2<L, R> {
+    #[inline(always)]
+    fn eq(&self, _: &Assert2<OL, OR>) -> bool {
+        Type::<(L, R)>::is::<(OL, OR)>()
+    }
+}
+
+impl<L: 'static, R: 'static, OL: 'static, OR: 'static> PartialOrd<Assert2<OL, OR>> for Assert2<L, R> {
+    #[inline(always)]
+    fn partial_cmp(&self, _: &Assert2<OL, OR>) -> Option<cmp::Ordering> {
+        Type::<(L, R)>::type_id().partial_cmp(&Type::<(OL, OR)>::type_id())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Type<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Type<{}>", Self::name())
+    }
+}
+
+impl<T> fmt::Debug for Assert<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Assert<{}> {{ size: {}, align: {} }}", Type::<T>::name(), Type::<T>::size(), Type::<T>::align())
+    }
+}
+
+impl<L, R> fmt::Debug for Assert2<L, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "Assert2<{}, {}> {{ size: ({}, {}), align: ({}, {}) }}",
+            Type::<L>::name(), Type::<R>::name(),
+            Type::<L>::size(), Type::<R>::size(),
+            Type::<L>::align(), Type::<R>::align()
+        )
+    }
+}
+
+impl<T> fmt::Debug for AssertAll<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "AssertAll<{}> {{ size: {}, align: {} }}", Type::<T>::name(), Type::<T>::size(), Type::<T>::align())
+    }
+}
@@ -0,0 +1,195 @@
+//!Information about unsized values
+
+use core::{any, mem};
+
+///Kind of pointer metadata
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Metadata {
+    ///Thin pointer, used by `Sized` types and extern types
+    None,
+    ///Number of elements, used by slices, `str` and types with slice tail
+    Length,
+    ///Pointer to virtual table, used by trait objects and types with trait object tail
+    VTable,
+    ///Pointer is not thin, but kind of its metadata cannot be determined
+    Unknown,
+}
+
+#[inline(always)]
+const fn is_fn_arrow(bytes: &[u8], idx: usize) -> bool {
+    idx > 0 && bytes[idx - 1] == b'-'
+}
+
+///Returns generic arguments of the outermost type within `name`, without enclosing brackets
+fn generic_arguments(name: &str) -> Option<&str> {
+    let args = name.strip_suffix('>')?;
+    let bytes = args.as_bytes();
+
+    let mut depth = 0usize;
+    let mut idx = bytes.len();
+    while idx > 0 {
+        idx -= 1;
+        match bytes[idx] {
+            b'>' if is_fn_arrow(bytes, idx) => (),
+            b'>' | b')' | b']' => depth += 1,
+            b'<' | b'(' | b'[' if depth > 0 => depth -= 1,
+            b'<' => return Some(&args[idx + 1..]),
+            _ => (),
+        }
+    }
+
+    None
+}
+
+///Iterator over comma separated arguments, ignoring commas within nested brackets
+struct Arguments<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Arguments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let bytes = self.rest.as_bytes();
+        let mut depth = 0usize;
+        for idx in 0..bytes.len() {
+            match bytes[idx] {
+                b'>' if is_fn_arrow(bytes, idx) => (),
+                b'<' | b'(' | b'[' => depth += 1,
+                b'>' | b')' | b']' => depth = depth.saturating_sub(1),
+                b',' if depth == 0 => {
+                    let arg = &self.rest[..idx];
+                    self.rest = &self.rest[idx + 1..];
+                    return Some(arg.trim());
+                },
+                _ => (),
+            }
+        }
+
+        let arg = self.rest;
+        self.rest = "";
+        Some(arg.trim())
+    }
+}
+
+///Returns metadata kind if `name` is trait object, slice or `str` itself
+fn direct_metadata_of_name(name: &str) -> Option<Metadata> {
+    if name.starts_with("dyn ") {
+        Some(Metadata::VTable)
+    } else if name == "str" {
+        Some(Metadata::Length)
+    } else if let Some(element) = name.strip_prefix('[').and_then(|name| name.strip_suffix(']')) {
+        //Array has length after top level `;`
+        match has_top_level_semicolon(element) {
+            true => None,
+            false => Some(Metadata::Length),
+        }
+    } else {
+        None
+    }
+}
+
+fn has_top_level_semicolon(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    for idx in 0..bytes.len() {
+        match bytes[idx] {
+            b'>' if is_fn_arrow(bytes, idx) => (),
+            b'<' | b'(' | b'[' => depth += 1,
+            b'>' | b')' | b']' => depth = depth.saturating_sub(1),
+            b';' if depth == 0 => return true,
+            _ => (),
+        }
+    }
+    false
+}
+
+fn metadata_of_name(name: &str) -> Metadata {
+    if let Some(metadata) = direct_metadata_of_name(name) {
+        return metadata;
+    }
+
+    let args = match generic_arguments(name) {
+        Some(args) => args,
+        None => return Metadata::Unknown,
+    };
+
+    //Prefer generic argument that is unsized itself
+    let mut result = None;
+    for arg in (Arguments { rest: args }) {
+        match (result, direct_metadata_of_name(arg)) {
+            (_, None) => (),
+            (None, metadata) => result = metadata,
+            (Some(current), Some(metadata)) if current != metadata => return Metadata::Unknown,
+            _ => (),
+        }
+    }
+
+    match result {
+        Some(metadata) => metadata,
+        //Otherwise assume unsized tail is nested within last argument
+        None => match (Arguments { rest: args }).last() {
+            Some(tail) => metadata_of_name(tail),
+            None => Metadata::Unknown,
+        },
+    }
+}
+
+impl Metadata {
+    ///Determines metadata kind of pointer to `T`
+    ///
+    ///Stable Rust provides no way to inspect pointer metadata, hence distinction between length
+    ///and virtual table relies on type name, which format is unspecified, making result best-effort only.
+    ///
+    ///For custom DST, unsized tail is looked up among its generic arguments (e.g. `Wrapper<dyn Trait, u8>`),
+    ///preferring argument, that is trait object, slice or `str` itself, otherwise searching within last argument.
+    ///When tail cannot be found (e.g. non-generic DST), [Unknown](#variant.Unknown) is returned.
+    pub fn of<T: ?Sized>() -> Self {
+        if mem::size_of::<*const T>() == mem::size_of::<*const ()>() {
+            return Metadata::None;
+        }
+
+        metadata_of_name(any::type_name::<T>())
+    }
+}
+
+///Information about value, possibly unsized.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValueInfo {
+    size: usize,
+    align: usize,
+    metadata: Metadata,
+}
+
+impl ValueInfo {
+    #[inline(always)]
+    pub(crate) const fn new(size: usize, align: usize, metadata: Metadata) -> Self {
+        Self {
+            size,
+            align,
+            metadata,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns value size
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline(always)]
+    ///Returns value minimum alignment
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+
+    #[inline(always)]
+    ///Returns kind of metadata within pointer to the value
+    pub const fn metadata(&self) -> Metadata {
+        self.metadata
+    }
+}
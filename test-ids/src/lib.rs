@@ -0,0 +1,56 @@
+//!Type ids computed within separate crate and its own codegen units.
+//!
+//!Used by `type_traits` tests to verify ids are consistent across crates.
+
+use type_traits::Type;
+
+use core::any::TypeId;
+
+pub struct Foo;
+pub struct Generic<T>(pub T);
+
+#[macro_export]
+///Invokes `$callback` with list of types, which ids are provided by [ids](fn.ids.html)
+macro_rules! shared_types {
+    ($callback:ident) => {
+        $callback!(
+            (), u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+            String, &'static str, &'static u8, *const u8, [u8; 1], &'static [u8],
+            Option<u8>, Result<u8, ()>, Vec<u8>,
+            Box<dyn core::fmt::Debug>, Box<dyn core::fmt::Debug + Send>,
+            for<'a> fn(&'a (), &'a ()), fn(&'static (), &'static ()),
+            str, [u8], dyn core::fmt::Debug,
+            type_traits::Type<u8>, type_traits::TypeInfo,
+            $crate::Foo, $crate::Generic<u8>, $crate::Generic<$crate::Foo>,
+        )
+    };
+}
+
+macro_rules! collect_ids {
+    ($($typ:ty),+ $(,)?) => {
+        vec![$((stringify!($typ), Type::<$typ>::type_id())),+]
+    };
+}
+
+mod first {
+    #[inline(never)]
+    pub fn ids() -> Vec<(&'static str, super::TypeId)> {
+        use super::Type;
+        shared_types!(collect_ids)
+    }
+}
+
+mod second {
+    #[inline(never)]
+    pub fn ids() -> Vec<(&'static str, super::TypeId)> {
+        use super::Type;
+        shared_types!(collect_ids)
+    }
+}
+
+///Returns ids of [shared_types](macro.shared_types.html), computed within this crate.
+pub fn ids() -> Vec<(&'static str, TypeId)> {
+    let ids = first::ids();
+    assert_eq!(ids, second::ids());
+    ids
+}
@@ -0,0 +1,94 @@
+use type_traits::{Check, Check2, LayoutMismatch, Relation, TypeInfo};
+
+use core::num::NonZeroU32;
+
+#[test]
+fn should_check_single_type() {
+    assert_eq!(Check::<u32>::no_need_drop(), Ok(()));
+    assert_eq!(Check::<String>::no_need_drop(), Err(LayoutMismatch::NeedsDrop));
+    assert_eq!(Check::<u8>::is_not_zst(), Ok(()));
+    assert_eq!(Check::<()>::is_not_zst(), Err(LayoutMismatch::Size { actual: 0, relation: Relation::NotEqual, expected: 0 }));
+    assert_eq!(Check::<()>::is_zst(), Ok(()));
+    assert_eq!(Check::<u16>::is_zst(), Err(LayoutMismatch::Size { actual: 2, relation: Relation::Equal, expected: 0 }));
+    assert_eq!(Check::<NonZeroU32>::has_niche(), Ok(()));
+    assert_eq!(Check::<u32>::has_niche(), Err(LayoutMismatch::Niche { actual: 8, expected: 4 }));
+    assert_eq!(Check::<&u8>::is_null_pointer_optimized(), Ok(()));
+    assert_eq!(Check::<NonZeroU32>::is_null_pointer_optimized().is_err(), core::mem::size_of::<usize>() != 4);
+    assert_eq!(Check::<u32>::has_no_padding(), Ok(()));
+}
+
+#[test]
+fn should_check_type_against_value() {
+    assert_eq!(Check::<u32>::size_is(4), Ok(()));
+    assert_eq!(Check::<u32>::size_at_most(2), Err(LayoutMismatch::Size { actual: 4, relation: Relation::AtMost, expected: 2 }));
+    assert_eq!(Check::<u32>::size_at_least(8), Err(LayoutMismatch::Size { actual: 4, relation: Relation::AtLeast, expected: 8 }));
+    assert_eq!(Check::<u16>::align_is(2), Ok(()));
+    assert_eq!(Check::<u16>::align_at_least(4), Err(LayoutMismatch::Align { actual: 2, relation: Relation::AtLeast, expected: 4 }));
+    assert_eq!(Check::<u16>::align_at_most(1), Err(LayoutMismatch::Align { actual: 2, relation: Relation::AtMost, expected: 1 }));
+}
+
+#[test]
+fn should_check_type_list() {
+    assert_eq!(Check::<(u32, i32, f32)>::all_same_size(), Ok(()));
+    assert_eq!(Check::<(u32, u64)>::all_same_size(), Err(LayoutMismatch::ElementSize { index: 1, actual: 8, relation: Relation::Equal, expected: 4 }));
+    assert_eq!(Check::<(u8, [u16; 2])>::all_same_align(), Err(LayoutMismatch::ElementAlign { index: 1, actual: 2, relation: Relation::Equal, expected: 1 }));
+    assert_eq!(Check::<(u8, bool)>::none_need_drop(), Ok(()));
+    assert_eq!(Check::<(u8, String)>::none_need_drop(), Err(LayoutMismatch::NeedsDrop));
+    assert_eq!(Check::<(u8, u32, [u8; 4])>::all_fit_in(4), Ok(()));
+    assert_eq!(Check::<(u8, u64, u16)>::all_fit_in(4), Err(LayoutMismatch::ElementSize { index: 1, actual: 8, relation: Relation::AtMost, expected: 4 }));
+    assert_eq!(Check::<()>::all_fit_in(0), Ok(()));
+    assert_eq!(Check::<(u8, u8, u16)>::all_same_size(), Err(LayoutMismatch::ElementSize { index: 2, actual: 2, relation: Relation::Equal, expected: 1 }));
+}
+
+#[test]
+fn should_check_pair_of_types() {
+    assert_eq!(Check2::<u32, f32>::is_same_size(), Ok(()));
+    assert_eq!(Check2::<u32, [u8; 4]>::is_same_align(), Err(LayoutMismatch::Align { actual: 4, relation: Relation::Equal, expected: 1 }));
+    assert_eq!(Check2::<u32, u16>::is_left_size_greater_or_equal(), Ok(()));
+    assert_eq!(Check2::<u32, u16>::is_left_size_less(), Err(LayoutMismatch::Size { actual: 4, relation: Relation::Less, expected: 2 }));
+    assert_eq!(Check2::<[u8; 6], u16>::is_left_size_multiple(), Ok(()));
+    assert_eq!(Check2::<[u8; 6], ()>::is_left_size_multiple(), Err(LayoutMismatch::Size { actual: 6, relation: Relation::MultipleOf, expected: 0 }));
+    assert_eq!(Check2::<u32, u8>::is_left_align_greater_or_equal(), Ok(()));
+    assert_eq!(Check2::<u32, u8>::is_left_align_less(), Err(LayoutMismatch::Align { actual: 4, relation: Relation::Less, expected: 1 }));
+    assert_eq!(Check2::<u32, u32>::is_same_type(), Ok(()));
+    assert_eq!(Check2::<u32, i32>::is_same_type(), Err(LayoutMismatch::Type { actual: "u32", expected: "i32" }));
+}
+
+#[test]
+fn should_check_runtime_type_info() {
+    let received = TypeInfo::of::<[u32; 2]>();
+
+    assert_eq!(received.check_layout(&TypeInfo::of::<u64>()), Err(LayoutMismatch::Align { actual: 4, relation: Relation::Equal, expected: 8 }));
+    assert_eq!(received.check_layout(&TypeInfo::of::<[f32; 2]>()), Ok(()));
+    assert!(received.check_type(&TypeInfo::of::<[f32; 2]>()).is_err());
+    assert_eq!(received.check_type(&TypeInfo::of::<[u32; 2]>()), Ok(()));
+
+    assert_eq!(received.check_no_need_drop(), Ok(()));
+    assert_eq!(TypeInfo::of::<String>().check_no_need_drop(), Err(LayoutMismatch::NeedsDrop));
+    assert_eq!(received.check_not_zst(), Ok(()));
+    assert_eq!(received.check_zst(), Err(LayoutMismatch::Size { actual: 8, relation: Relation::Equal, expected: 0 }));
+    assert_eq!(TypeInfo::of::<()>().check_not_zst(), Err(LayoutMismatch::Size { actual: 0, relation: Relation::NotEqual, expected: 0 }));
+    assert_eq!(received.check_size_is(8), Ok(()));
+    assert_eq!(received.check_size_at_most(4), Err(LayoutMismatch::Size { actual: 8, relation: Relation::AtMost, expected: 4 }));
+    assert_eq!(received.check_size_at_least(16), Err(LayoutMismatch::Size { actual: 8, relation: Relation::AtLeast, expected: 16 }));
+    assert_eq!(received.check_align_is(4), Ok(()));
+    assert_eq!(received.check_align_at_least(8), Err(LayoutMismatch::Align { actual: 4, relation: Relation::AtLeast, expected: 8 }));
+    assert_eq!(received.check_align_at_most(2), Err(LayoutMismatch::Align { actual: 4, relation: Relation::AtMost, expected: 2 }));
+    assert_eq!(received.check_same_size(&TypeInfo::of::<u64>()), Ok(()));
+    assert_eq!(received.check_same_align(&TypeInfo::of::<u64>()), Err(LayoutMismatch::Align { actual: 4, relation: Relation::Equal, expected: 8 }));
+    assert_eq!(received.check_size_multiple_of(&TypeInfo::of::<u16>()), Ok(()));
+    assert_eq!(received.check_size_multiple_of(&TypeInfo::of::<[u8; 3]>()), Err(LayoutMismatch::Size { actual: 8, relation: Relation::MultipleOf, expected: 3 }));
+}
+
+#[test]
+fn should_display_mismatch() {
+    let error = Check2::<[u8; 24], [u8; 16]>::is_same_size().unwrap_err();
+    assert_eq!(error.to_string(), "size (24) must equal 16");
+    assert_eq!(LayoutMismatch::NeedsDrop.to_string(), "type must not need drop");
+    assert_eq!(LayoutMismatch::Padding { actual: 3 }.to_string(), "padding bytes (3) must be 0");
+    let error = Check::<(u8, u8, u16)>::all_fit_in(1).unwrap_err();
+    assert_eq!(error.to_string(), "size of element 2 (2) must be less or equal to 1");
+
+    let error: &dyn std::error::Error = &LayoutMismatch::NeedsDrop;
+    assert_eq!(error.to_string(), "type must not need drop");
+}
@@ -0,0 +1,41 @@
+use type_traits::{impl_type_facts, Bool, Boolean, False, If, True, Type, TypeFacts};
+
+struct Marker;
+struct Large {
+    _data: [u64; 4],
+}
+struct Droppable {
+    _data: Box<u8>,
+}
+impl_type_facts!(Marker, Large, Droppable);
+
+const _: () = assert!(True::VALUE);
+const _: () = assert!(!False::VALUE);
+const _: () = assert!(!<True as Boolean>::Not::VALUE);
+const _: () = assert!(<True as Boolean>::And::<True>::VALUE);
+const _: () = assert!(!<True as Boolean>::And::<False>::VALUE);
+const _: () = assert!(<False as Boolean>::Or::<True>::VALUE);
+const _: () = assert!(!<False as Boolean>::Or::<False>::VALUE);
+const _: () = assert!(Bool::<{ Type::<u8>::size() == 1 }>::VALUE);
+
+const _: () = assert!(<Marker as TypeFacts>::IsZst::VALUE);
+const _: () = assert!(!<Large as TypeFacts>::IsZst::VALUE);
+const _: () = assert!(!<Large as TypeFacts>::FitsInPointer::VALUE);
+const _: () = assert!(<u8 as TypeFacts>::FitsInPointer::VALUE);
+const _: () = assert!(<bool as TypeFacts>::HasNiche::VALUE);
+const _: () = assert!(<Droppable as TypeFacts>::NeedsDrop::VALUE);
+const _: () = assert!(!<u32 as TypeFacts>::NeedsDrop::VALUE);
+
+type Holder<T> = If<<T as TypeFacts>::NeedsDrop, Option<T>, T>;
+
+fn holder_is<T: TypeFacts + 'static, Expected: 'static>() -> bool {
+    Type::<Holder<T>>::is::<Expected>()
+}
+
+#[test]
+fn should_select_type_in_generic_code() {
+    assert!(holder_is::<u32, u32>());
+    assert!(holder_is::<Droppable, Option<Droppable>>());
+    assert!(Type::<If<True, u8, u16>>::is::<u8>());
+    assert!(Type::<If<False, u8, u16>>::is::<u16>());
+}
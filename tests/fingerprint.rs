@@ -0,0 +1,67 @@
+use type_traits::{Type, StableName, stable_name_hash};
+
+#[repr(C)]
+struct Header {
+    _len: u32,
+    _flags: u32,
+}
+
+impl StableName for Header {
+    const NAME: &'static str = "Header";
+    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[]);
+}
+
+#[repr(C)]
+struct Droppable {
+    _len: u32,
+    _flags: u32,
+}
+
+impl Drop for Droppable {
+    fn drop(&mut self) {
+    }
+}
+
+impl StableName for Droppable {
+    const NAME: &'static str = "Header";
+    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[]);
+}
+
+#[test]
+fn should_have_pinned_fingerprint() {
+    //Fingerprint must never change, as it is stored by persistent formats
+    assert_eq!(Type::<u8>::fingerprint(), 0x179C6022FF977A68);
+    assert_eq!(Type::<u32>::fingerprint(), 0x6ED28023794D8763);
+    assert_eq!(Type::<Header>::fingerprint(), 0xBEA5E0823296F9A4);
+}
+
+#[test]
+fn should_differ_on_layout_with_the_same_name() {
+    assert_ne!(Type::<Header>::fingerprint(), Type::<Droppable>::fingerprint());
+}
+
+#[repr(C)]
+struct Segment<T> {
+    _header: Header,
+    _data: [T; 4],
+}
+
+impl<T: StableName> StableName for Segment<T> {
+    const NAME: &'static str = "Segment";
+    const NAME_HASH: u64 = stable_name_hash(Self::NAME, &[T::NAME_HASH]);
+}
+
+#[test]
+fn should_combine_fingerprint_of_components() {
+    const ARRAY: u64 = Type::<[u8; 16]>::fingerprint();
+
+    assert_ne!(ARRAY, Type::<[i8; 16]>::fingerprint());
+    assert_ne!(ARRAY, Type::<[u8; 15]>::fingerprint());
+    assert_ne!(ARRAY, Type::<[u16; 8]>::fingerprint());
+    assert_ne!(Type::<(u8, u16)>::fingerprint(), Type::<(u16, u8)>::fingerprint());
+    assert_ne!(Type::<Option<&u32>>::fingerprint(), Type::<Option<&i32>>::fingerprint());
+    assert_ne!(Type::<*const u8>::fingerprint(), Type::<*mut u8>::fingerprint());
+    assert_ne!(Type::<&[u8]>::fingerprint(), Type::<&str>::fingerprint());
+    assert_ne!(Type::<Segment<u32>>::fingerprint(), Type::<Segment<f32>>::fingerprint());
+    assert_ne!(Type::<Segment<(u8, [u8; 3])>>::fingerprint(), Type::<Segment<[u8; 4]>>::fingerprint());
+}
@@ -0,0 +1,34 @@
+use type_traits::{Type, Metadata};
+
+use core::fmt;
+
+struct Wrapper<A, T: ?Sized>(A, T);
+
+struct Wrap<T: ?Sized, A>(A, T);
+
+trait Tr {}
+
+struct Dst {
+    _a: u8,
+    _tail: dyn Tr,
+}
+
+#[test]
+fn should_detect_metadata_by_unsized_tail() {
+    assert_eq!(Type::<Wrapper<Box<dyn fmt::Debug>, [u8]>>::metadata(), Metadata::Length);
+    assert_eq!(Type::<Wrapper<Box<dyn fmt::Debug>, str>>::metadata(), Metadata::Length);
+    assert_eq!(Type::<Wrapper<[u8; 4], dyn fmt::Debug>>::metadata(), Metadata::VTable);
+    assert_eq!(Type::<Wrapper<u8, dyn fmt::Debug + Send>>::metadata(), Metadata::VTable);
+    assert_eq!(Type::<Wrapper<fn() -> u8, Wrapper<u8, dyn fmt::Display>>>::metadata(), Metadata::VTable);
+    assert_eq!(Type::<Wrapper<Box<dyn fmt::Debug>, Wrapper<(u8, u16), [fn() -> u8]>>>::metadata(), Metadata::Length);
+    assert_eq!(Type::<Wrapper<Box<dyn fmt::Debug>, u8>>::metadata(), Metadata::None);
+    assert_eq!(Type::<Wrap<[u8], Box<dyn fmt::Debug>>>::metadata(), Metadata::Length);
+    assert_eq!(Type::<Wrap<dyn Tr, u8>>::metadata(), Metadata::VTable);
+    assert_eq!(Type::<Wrap<[u8], [u8; 4]>>::metadata(), Metadata::Length);
+}
+
+#[test]
+fn should_report_unknown_metadata_without_tail() {
+    assert_eq!(Type::<Dst>::metadata(), Metadata::Unknown);
+    assert_eq!(Type::<Wrapper<u8, Dst>>::metadata(), Metadata::Unknown);
+}
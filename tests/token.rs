@@ -0,0 +1,56 @@
+use type_traits::{assert_impl, Assert, Assert2, Type};
+
+use core::cell::Cell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+assert_impl!(Type<Rc<Cell<u8>>>: Send + Sync + Copy + Default + core::fmt::Debug);
+assert_impl!(Type<str>: Send + Sync + Copy + Default + Eq + Ord + core::hash::Hash + core::fmt::Debug);
+assert_impl!(Type<[u8]>: core::fmt::Debug);
+assert_impl!(Type<dyn core::fmt::Display>: core::fmt::Debug);
+assert_impl!(Assert<Rc<u8>>: Send + Sync + Copy + Default + core::fmt::Debug + Eq + Ord);
+assert_impl!(Assert2<Rc<u8>, *const u8>: Send + Sync + Copy + Default + core::fmt::Debug + Eq + Ord);
+
+fn shorten<'a>(token: Type<&'static str>) -> Type<&'a str> {
+    token
+}
+
+#[test]
+fn should_be_zero_sized() {
+    assert!(Type::<Type<String>>::is_zst());
+    assert!(Type::<Assert<String>>::is_zst());
+    assert!(Type::<Assert2<String, u8>>::is_zst());
+}
+
+#[test]
+fn should_compare_across_types() {
+    assert_eq!(Type::<u8>::new(), Type::<u8>::default());
+    assert_ne!(Type::<u8>::new(), Type::<u16>::new());
+    assert!(Type::<str>::new() != Type::<[u8]>::new());
+    assert_eq!(Assert::<u8>::new(), Assert::<u8>::new());
+    assert_ne!(Assert::<u8>::new(), Assert::<i8>::new());
+    assert_eq!(Assert2::<u8, u16>::new(), Assert2::<u8, u16>::new());
+    assert_ne!(Assert2::<u8, u16>::new(), Assert2::<u16, u8>::new());
+
+    let ordering = Type::<u8>::new().partial_cmp(&Type::<u16>::new()).unwrap();
+    assert_eq!(ordering, Type::<u8>::type_id().cmp(&Type::<u16>::type_id()));
+    assert_eq!(Type::<u8>::new().partial_cmp(&Type::<u8>::new()), Some(core::cmp::Ordering::Equal));
+}
+
+#[test]
+fn should_hash_by_type() {
+    let mut set = HashSet::new();
+    assert!(set.insert(Type::<u8>::new()));
+    assert!(!set.insert(Type::<u8>::new()));
+}
+
+#[test]
+fn should_debug_format() {
+    let _ = shorten(Type::new());
+
+    assert_eq!(format!("{:?}", Type::<u32>::new()), "Type<u32>");
+    assert_eq!(format!("{:?}", Type::<str>::new()), "Type<str>");
+    assert_eq!(format!("{:?}", Type::<dyn core::fmt::Display>::new()), "Type<dyn core::fmt::Display>");
+    assert_eq!(format!("{:?}", Assert::<u32>::new()), "Assert<u32> { size: 4, align: 4 }");
+    assert_eq!(format!("{:?}", Assert2::<u32, u8>::new()), "Assert2<u32, u8> { size: (4, 1), align: (4, 1) }");
+}
@@ -0,0 +1,50 @@
+use type_traits::{Tid, TypeEq, cast_tid, cast_tid_ref};
+
+fn coerce_all<L, R>(eq: TypeEq<L, R>, value: L, mut values: [L; 2]) -> (R, Option<R>, [R; 1]) {
+    let first: &R = eq.coerce_ref(&values[0]);
+    let _ = first;
+    let slice: &mut [R] = eq.coerce_slice_mut(&mut values);
+    assert_eq!(slice.len(), 2);
+
+    let [first, second] = values;
+    (eq.coerce(value), eq.coerce_option(Some(first)), eq.coerce_array([second]))
+}
+
+#[test]
+fn should_coerce_values_without_double_drop() {
+    let eq = TypeEq::<String, String>::new();
+    let (value, option, array) = coerce_all(eq, "value".to_owned(), ["first".to_owned(), "second".to_owned()]);
+    assert_eq!(value, "value");
+    assert_eq!(option.as_deref(), Some("first"));
+    assert_eq!(array, ["second"]);
+}
+
+#[test]
+fn should_coerce_unsized_references() {
+    let eq = TypeEq::<str, str>::NEW.flip();
+    assert_eq!(eq.coerce_ref("text"), "text");
+
+    let mut bytes = [1u8, 2];
+    let eq = TypeEq::<[u8], [u8]>::new();
+    eq.coerce_mut(&mut bytes[..])[0] = 0;
+    assert_eq!(TypeEq::<u8, u8>::new().coerce_slice(&bytes), [0, 2]);
+}
+
+fn first_word<'a, T: Tid<'a>>(value: T) -> Option<&'a str> {
+    match cast_tid::<T, Option<&'a str>>(value) {
+        Ok(text) => text.and_then(|text| text.split(' ').next()),
+        Err(_) => None,
+    }
+}
+
+#[test]
+fn should_cast_borrowed_types_via_tid() {
+    let text = String::from("borrowed text");
+    assert_eq!(first_word(Some(text.as_str())), Some("borrowed"));
+    assert_eq!(first_word(Some(1u8)), None);
+
+    let slice = [text.as_str()];
+    assert_eq!(cast_tid_ref::<[&str; 1], [&str; 1]>(&slice), Some(&slice));
+    assert!(cast_tid_ref::<[&str; 1], [&[u8]; 1]>(&slice).is_none());
+    assert!(TypeEq::<&str, &str>::try_new_tid().is_some());
+}
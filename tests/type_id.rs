@@ -0,0 +1,98 @@
+use type_traits::Type;
+
+use core::any::TypeId;
+use std::collections::HashMap;
+
+mod first {
+    pub struct Foo;
+    pub struct Generic<T>(pub T);
+
+    #[inline(never)]
+    pub fn id<T: ?Sized + 'static>() -> core::any::TypeId {
+        type_traits::Type::<T>::type_id()
+    }
+}
+
+mod second {
+    pub struct Foo;
+    pub struct Generic<T>(pub T);
+
+    #[inline(never)]
+    pub fn id<T: ?Sized + 'static>() -> core::any::TypeId {
+        type_traits::Type::<T>::type_id()
+    }
+}
+
+macro_rules! collect_ids {
+    ($($typ:ty),+ $(,)?) => {{
+        let mut map = HashMap::new();
+        $(
+            let name = stringify!($typ);
+            let id = Type::<$typ>::type_id();
+            assert_eq!(id, TypeId::of::<$typ>(), "{} id differs from TypeId", name);
+            assert_eq!(id, first::id::<$typ>(), "{} id differs across codegen units", name);
+            assert_eq!(id, second::id::<$typ>(), "{} id differs across codegen units", name);
+            if let Some(unexpected) = map.insert(id, name) {
+                panic!("{} has id collision with {}", name, unexpected);
+            }
+            if let Some(unexpected) = map.insert(Type::<Type<$typ>>::type_id(), name) {
+                panic!("Type<{}> has id collision with {}", name, unexpected);
+            }
+        )+
+        map
+    }};
+}
+
+#[test]
+fn should_have_unique_type_ids() {
+    const CONST_ID: TypeId = Type::<first::Foo>::type_id();
+
+    let map = collect_ids!(
+        (), core::marker::PhantomData<()>,
+        u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+        String, &'static str, &'static u8, &'static mut u8, *const u8, *mut u8,
+        [u8; 1], [u8; 2], &'static [u8],
+        Option<u8>, Result<u8, ()>, Result<(), u8>,
+        first::Foo, second::Foo,
+        first::Generic<u8>, second::Generic<u8>,
+        first::Generic<first::Foo>, first::Generic<second::Foo>,
+        Box<dyn core::fmt::Debug>,
+        Box<dyn core::fmt::Debug + Send>,
+        Box<dyn core::fmt::Debug + Send + Sync>,
+        for<'a> fn(&'a (), &'a ()),
+        fn(&'static (), &'static ()),
+        fn(()),
+        Vec<u8>, Vec<i8>,
+        str, [u8], [i8], dyn core::fmt::Debug, dyn core::fmt::Display,
+    );
+
+    assert_eq!(map[&CONST_ID], "first::Foo");
+}
+
+#[test]
+fn should_have_same_id_for_reexported_type() {
+    extern crate alloc;
+
+    assert_eq!(Type::<alloc::vec::Vec<u8>>::type_id(), Type::<std::vec::Vec<u8>>::type_id());
+    assert_eq!(Type::<&'static str>::type_id(), first::id::<&'static str>());
+}
+
+macro_rules! local_ids {
+    ($($typ:ty),+ $(,)?) => {
+        vec![$((stringify!($typ), Type::<$typ>::type_id())),+]
+    };
+}
+
+#[test]
+fn should_have_same_id_across_crates() {
+    let external = type_traits_test_ids::ids();
+    let local = type_traits_test_ids::shared_types!(local_ids);
+
+    assert_eq!(external.len(), local.len());
+    for ((name, external), (_, local)) in external.iter().zip(local.iter()) {
+        assert_eq!(external, local, "{} id differs across crates", name);
+    }
+
+    assert_ne!(Type::<type_traits_test_ids::Foo>::type_id(), Type::<first::Foo>::type_id());
+    assert_ne!(Type::<type_traits_test_ids::Generic<u8>>::type_id(), Type::<first::Generic<u8>>::type_id());
+}
@@ -0,0 +1,55 @@
+#![allow(clippy::let_unit_value)]
+
+use type_traits::{Assert, AssertAll, AllFitIn, TypeInfo, TypeList};
+
+type Empty = ();
+type Max = (u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, bool, char, usize, String);
+
+const _: () = assert!(!Empty::ANY_NEEDS_DROP);
+const _: () = assert!(Max::ANY_NEEDS_DROP);
+
+#[test]
+fn should_describe_empty_list() {
+    assert_eq!(Empty::LEN, 0);
+    assert_eq!(Empty::MAX_SIZE, 0);
+    assert_eq!(Empty::MAX_ALIGN, 1);
+    assert_eq!(Empty::TOTAL_SIZE, 0);
+    assert!(Empty::infos().is_empty());
+}
+
+#[test]
+fn should_describe_list_of_16_elements() {
+    assert_eq!(Max::LEN, 16);
+    assert_eq!(Max::infos().len(), 16);
+    assert_eq!(Max::infos()[15], TypeInfo::of::<String>());
+    assert_eq!(Max::SIZES[4], 16);
+    assert_eq!(Max::MAX_SIZE, core::mem::size_of::<String>().max(16));
+    assert_eq!(Max::MAX_ALIGN, core::mem::align_of::<u128>());
+    assert_eq!(Max::TOTAL_SIZE, Max::infos().iter().map(TypeInfo::size).sum::<usize>());
+}
+
+fn assert_message_payloads<A, B, C, D, E>() {
+    let _ = Assert::<(A, B, C, D, E)>::NONE_NEED_DROP;
+    let _ = Assert::<(A, B, C, D, E)>::ALL_SAME_SIZE;
+}
+
+#[test]
+fn should_assert_aggregate_properties_in_generic_code() {
+    assert_message_payloads::<u32, i32, f32, char, [u8; 4]>();
+    let _ = Assert::<(u8, bool, i8)>::ALL_SAME_ALIGN;
+}
+
+fn assert_all<A, B, C, D>() {
+    let _ = AssertAll::<(A, B, C, D)>::SAME_SIZE;
+    let _ = AssertAll::<(A, B, C, D)>::SAME_ALIGN;
+    let _ = AssertAll::<(A, B, C, D)>::NONE_NEED_DROP;
+    let _ = AllFitIn::<(A, B, C, D), 4>::ASSERT;
+}
+
+#[test]
+fn should_assert_all_elements_of_list() {
+    assert_all::<u32, i32, f32, char>();
+    let _ = AssertAll::<Empty>::SAME_SIZE;
+    let _ = AllFitIn::<Empty, 0>::ASSERT;
+    assert_eq!(format!("{:?}", AssertAll::<(u8,)>::new()), "AssertAll<(u8,)> { size: 1, align: 1 }");
+}